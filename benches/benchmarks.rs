@@ -1,6 +1,8 @@
 use criterion::criterion_main;
 
+mod solutions;
 mod year_2015;
+mod year_2016;
 
 criterion_main! {
     year_2015::day_01::benches,
@@ -17,4 +19,17 @@ criterion_main! {
     year_2015::day_12::benches,
     year_2015::day_13::benches,
     year_2015::day_14::benches,
+    year_2015::day_15::benches,
+    year_2015::day_16::benches,
+    year_2015::day_17::benches,
+    year_2015::day_18::benches,
+    year_2015::day_19::benches,
+    year_2015::day_20::benches,
+    year_2015::day_21::benches,
+    year_2015::day_22::benches,
+    year_2015::day_23::benches,
+    year_2015::day_24::benches,
+    year_2015::day_25::benches,
+    year_2016::day_01::benches,
+    solutions::benches,
 }
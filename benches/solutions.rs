@@ -0,0 +1,26 @@
+use std::fs;
+
+use criterion::{black_box, criterion_group, Criterion};
+
+fn solve_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("solutions");
+    group.sample_size(10);
+
+    // Puzzles without a checked in input are skipped.
+    for puzzle in advent_of_code::puzzles() {
+        let path = format!("input/{}/day-{:02}.txt", puzzle.year, puzzle.day);
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) => continue,
+        };
+
+        let id = format!("{} day {:02} - solve file", puzzle.year, puzzle.day);
+        group.bench_function(id, |b| {
+            b.iter(|| puzzle.solve(black_box(&contents)));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, solve_benchmark);
@@ -1,8 +1,10 @@
 use std::fs;
+use std::io;
+use std::io::{BufReader, Read};
 
 use criterion::{black_box, criterion_group, Criterion};
 
-use advent_of_code::year_2015::day_01::{floor, position_to_floor};
+use advent_of_code::year_2015::day_01::{floor, floor_from_reader, position_to_floor};
 
 fn floor_benchmark(c: &mut Criterion) {
     let contents = fs::read("input/2015/day-01.txt").expect("Failed to read file.");
@@ -13,6 +15,15 @@ fn floor_benchmark(c: &mut Criterion) {
     c.bench_function("year_2015::day_01 - floor/file", |b| {
         b.iter(|| floor(black_box(&contents)))
     });
+    c.bench_function("year_2015::day_01 - floor_from_reader/file", |b| {
+        b.iter(|| floor_from_reader(black_box(&contents[..])))
+    });
+    c.bench_function("year_2015::day_01 - floor_from_reader/repeat 16MiB", |b| {
+        b.iter(|| {
+            let reader = BufReader::new(io::repeat(b'(').take(black_box(1 << 24)));
+            floor_from_reader(reader)
+        })
+    });
 }
 
 fn position_benchmark(c: &mut Criterion) {
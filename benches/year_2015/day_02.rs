@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use criterion::{black_box, criterion_group, Criterion};
 
+use advent_of_code::util::parse_lines;
 use advent_of_code::year_2015::day_02::Present;
 
 fn wrapping_benchmark(c: &mut Criterion) {
@@ -17,11 +18,7 @@ fn wrapping_benchmark(c: &mut Criterion) {
     });
     c.bench_function("year_2015::day_02 - wrapping paper/file", |b| {
         b.iter(|| {
-            let presents: Vec<Present> = contents
-                .lines()
-                .map(|s| Present::from_str(black_box(s)))
-                .filter_map(Result::ok)
-                .collect();
+            let presents: Vec<Present> = parse_lines(black_box(&contents)).unwrap();
 
             let _: u32 = presents.iter().map(Present::wrapping_paper_needed).sum();
         });
@@ -40,11 +37,7 @@ fn ribbon_benchmark(c: &mut Criterion) {
     });
     c.bench_function("year_2015::day_02 - ribbon/file", |b| {
         b.iter(|| {
-            let presents: Vec<Present> = contents
-                .lines()
-                .map(|s| Present::from_str(black_box(s)))
-                .filter_map(Result::ok)
-                .collect();
+            let presents: Vec<Present> = parse_lines(black_box(&contents)).unwrap();
 
             let _: u32 = presents.iter().map(Present::ribbon_needed).sum();
         });
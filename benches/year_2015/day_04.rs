@@ -1,13 +1,19 @@
+use std::fs;
+
 use criterion::{black_box, criterion_group, Criterion};
 
 use advent_of_code::year_2015::day_04::find_number;
 
 fn find_number_benchmark(c: &mut Criterion) {
-    c.bench_function("year_2015::day_04 - find_number iwrupvqb/5", |b| {
-        b.iter(|| find_number(black_box(b"iwrupvqb"), black_box(5)));
+    let contents =
+        fs::read_to_string("input/2015/day-04.txt").expect("Failed to read file to string.");
+    let key = contents.trim().as_bytes();
+
+    c.bench_function("year_2015::day_04 - find_number file/5", |b| {
+        b.iter(|| find_number(black_box(key), black_box(5)));
     });
-    c.bench_function("year_2015::day_04 - find_number iwrupvqb/6", |b| {
-        b.iter(|| find_number(black_box(b"iwrupvqb"), black_box(6)));
+    c.bench_function("year_2015::day_04 - find_number file/6", |b| {
+        b.iter(|| find_number(black_box(key), black_box(6)));
     });
 }
 
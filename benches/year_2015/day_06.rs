@@ -1,9 +1,11 @@
 use std::fs;
-use std::str::FromStr;
 
 use criterion::{black_box, criterion_group, Criterion};
 
-use advent_of_code::year_2015::day_06::{AdjustableBulb, LightGrid, LightInstruction, SimpleBulb};
+use advent_of_code::util::parse_lines;
+use advent_of_code::year_2015::day_06::{
+    AdjustableBulb, LightGrid, LightInstruction, Point, SimpleBulb, Storage,
+};
 
 fn apply_operation_benchmark(c: &mut Criterion) {
     let contents =
@@ -12,11 +14,10 @@ fn apply_operation_benchmark(c: &mut Criterion) {
     c.bench_function("year_2015::day_06 - apply_operation SimpleBulb", |b| {
         b.iter(|| {
             let mut grid = LightGrid::<SimpleBulb>::new();
-            contents
-                .lines()
-                .map(|s| LightInstruction::from_str(black_box(s)))
-                .filter_map(Result::ok)
-                .for_each(|instruction| grid.apply_operation(black_box(&instruction)));
+            parse_lines::<LightInstruction>(black_box(&contents))
+                .unwrap()
+                .into_iter()
+                .for_each(|instruction| grid.apply_operation(black_box(&instruction)).unwrap());
             let _ = grid.total_brightness();
         });
     });
@@ -24,14 +25,83 @@ fn apply_operation_benchmark(c: &mut Criterion) {
     c.bench_function("year_2015::day_06 - apply_operation AdjustableBulb", |b| {
         b.iter(|| {
             let mut grid = LightGrid::<AdjustableBulb>::new();
-            contents
-                .lines()
-                .map(|s| LightInstruction::from_str(black_box(s)))
-                .filter_map(Result::ok)
-                .for_each(|instruction| grid.apply_operation(black_box(&instruction)));
+            parse_lines::<LightInstruction>(black_box(&contents))
+                .unwrap()
+                .into_iter()
+                .for_each(|instruction| grid.apply_operation(black_box(&instruction)).unwrap());
             let _ = grid.total_brightness();
         });
     });
 }
 
-criterion_group!(benches, apply_operation_benchmark);
+fn storage_benchmark(c: &mut Criterion) {
+    let contents =
+        fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
+    let instructions = parse_lines::<LightInstruction>(&contents).unwrap();
+
+    for &storage in &[Storage::Dense, Storage::Compressed] {
+        c.bench_function(
+            &format!("year_2015::day_06 - {:?} SimpleBulb", storage),
+            |b| {
+                b.iter(|| {
+                    let mut grid = LightGrid::<SimpleBulb>::with_storage(storage);
+                    instructions.iter().for_each(|instruction| {
+                        grid.apply_operation(black_box(instruction)).unwrap()
+                    });
+                    grid.total_brightness()
+                });
+            },
+        );
+
+        c.bench_function(
+            &format!("year_2015::day_06 - {:?} AdjustableBulb", storage),
+            |b| {
+                b.iter(|| {
+                    let mut grid = LightGrid::<AdjustableBulb>::with_storage(storage);
+                    instructions.iter().for_each(|instruction| {
+                        grid.apply_operation(black_box(instruction)).unwrap()
+                    });
+                    grid.total_brightness()
+                });
+            },
+        );
+    }
+}
+
+fn regions_benchmark(c: &mut Criterion) {
+    let contents =
+        fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
+    let mut grid = LightGrid::<AdjustableBulb>::new();
+    parse_lines::<LightInstruction>(&contents)
+        .unwrap()
+        .iter()
+        .for_each(|instruction| grid.apply_operation(instruction).unwrap());
+
+    c.bench_function("year_2015::day_06 - regions file", |b| {
+        b.iter(|| grid.regions());
+    });
+
+    let regions = grid.regions();
+    let (start, end) = (Point { x: 100, y: 100 }, Point { x: 899, y: 899 });
+    c.bench_function("year_2015::day_06 - regions brightness file", |b| {
+        b.iter(|| {
+            regions
+                .brightness(black_box(&start), black_box(&end))
+                .unwrap()
+        });
+    });
+    c.bench_function("year_2015::day_06 - regions max_brightness file", |b| {
+        b.iter(|| {
+            regions
+                .max_brightness(black_box(&start), black_box(&end))
+                .unwrap()
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    apply_operation_benchmark,
+    storage_benchmark,
+    regions_benchmark
+);
@@ -1,8 +1,8 @@
 use std::fs;
-use std::str::FromStr;
 
 use criterion::{black_box, criterion_group, Criterion};
 
+use advent_of_code::util::parse_lines;
 use advent_of_code::year_2015::day_07::{Circuit, CircuitInstruction};
 
 fn resolve_benchmark(c: &mut Criterion) {
@@ -12,12 +12,11 @@ fn resolve_benchmark(c: &mut Criterion) {
     c.bench_function("year_2015::day_07 - resolve sample", |b| {
         b.iter(|| {
             let mut circuit = Circuit::new();
-            contents
-                .lines()
-                .map(|s| CircuitInstruction::from_str(black_box(s)))
-                .filter_map(Result::ok)
+            parse_lines::<CircuitInstruction>(black_box(&contents))
+                .unwrap()
+                .into_iter()
                 .for_each(|instruction| circuit.add_instruction(black_box(instruction)));
-            circuit.resolve();
+            circuit.resolve().unwrap();
         });
     });
 
@@ -27,14 +26,75 @@ fn resolve_benchmark(c: &mut Criterion) {
     c.bench_function("year_2015::day_07 - resolve file", |b| {
         b.iter(|| {
             let mut circuit = Circuit::new();
-            contents
-                .lines()
-                .map(|s| CircuitInstruction::from_str(black_box(s)))
-                .filter_map(Result::ok)
+            parse_lines::<CircuitInstruction>(black_box(&contents))
+                .unwrap()
+                .into_iter()
                 .for_each(|instruction| circuit.add_instruction(black_box(instruction)));
-            circuit.resolve();
+            circuit.resolve().unwrap();
         });
     });
 }
 
-criterion_group!(benches, resolve_benchmark);
+fn override_wire_benchmark(c: &mut Criterion) {
+    let contents =
+        fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
+
+    let mut circuit = Circuit::new();
+    parse_lines::<CircuitInstruction>(&contents)
+        .unwrap()
+        .into_iter()
+        .for_each(|instruction| circuit.add_instruction(instruction));
+    circuit.resolve().unwrap();
+
+    let mut signal: u16 = 0;
+    c.bench_function("year_2015::day_07 - override_wire file/b", |b| {
+        b.iter(|| {
+            signal = signal.wrapping_add(1);
+            circuit.override_wire("b", black_box(signal)).unwrap();
+        });
+    });
+    c.bench_function("year_2015::day_07 - reset and resolve file", |b| {
+        b.iter(|| {
+            circuit.reset();
+            circuit.resolve().unwrap();
+        });
+    });
+}
+
+fn solve_benchmark(c: &mut Criterion) {
+    let contents =
+        fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
+
+    let mut circuit = Circuit::new();
+    parse_lines::<CircuitInstruction>(&contents)
+        .unwrap()
+        .into_iter()
+        .for_each(|instruction| circuit.add_instruction(instruction));
+
+    c.bench_function("year_2015::day_07 - solve file/b", |b| {
+        b.iter(|| circuit.solve(&["b"], "a", black_box(16076)).unwrap());
+    });
+
+    let mut circuit = Circuit::new();
+    parse_lines::<CircuitInstruction>(
+        "x AND y -> d\nx OR z -> e\nd LSHIFT 3 -> f\nNOT e -> g\nf OR g -> a",
+    )
+    .unwrap()
+    .into_iter()
+    .for_each(|instruction| circuit.add_instruction(instruction));
+
+    c.bench_function("year_2015::day_07 - solve_first x, y, z", |b| {
+        b.iter(|| {
+            circuit
+                .solve_first(&["x", "y", "z"], "a", black_box(0x1234))
+                .unwrap()
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    resolve_benchmark,
+    override_wire_benchmark,
+    solve_benchmark
+);
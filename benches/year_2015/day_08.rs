@@ -10,7 +10,7 @@ fn reformat_string_benchmark(c: &mut Criterion) {
 
     c.bench_function("year_2015::day_08 - reformat_string sample", |b| {
         b.iter(|| {
-            let list = vec![
+            let list = [
                 String::from(r#""""#),
                 String::from(r#""abc""#),
                 String::from(r#""aaa\"aaa""#),
@@ -40,7 +40,7 @@ fn escape_string_benchmark(c: &mut Criterion) {
 
     c.bench_function("year_2015::day_08 - escape_string sample", |b| {
         b.iter(|| {
-            let list = vec![
+            let list = [
                 String::from(r#""""#),
                 String::from(r#""abc""#),
                 String::from(r#""aaa\"aaa""#),
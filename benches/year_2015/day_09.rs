@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use criterion::{black_box, criterion_group, Criterion};
 
+use advent_of_code::util::parse_lines;
 use advent_of_code::year_2015::day_09::{Edge, Graph};
 
 fn shortest_path_benchmark(c: &mut Criterion) {
@@ -29,10 +30,9 @@ fn shortest_path_benchmark(c: &mut Criterion) {
     c.bench_function("year_2015::day_09 - shortest_path file", |b| {
         b.iter(|| {
             let mut graph = Graph::new();
-            contents
-                .lines()
-                .map(|s| Edge::from_str(black_box(s)))
-                .filter_map(Result::ok)
+            parse_lines::<Edge>(black_box(&contents))
+                .unwrap()
+                .into_iter()
                 .for_each(|edge| graph.add_edge(black_box(edge)));
 
             let _ = graph.shortest_path();
@@ -64,10 +64,9 @@ fn longest_path_benchmark(c: &mut Criterion) {
     c.bench_function("year_2015::day_09 - longest_path file", |b| {
         b.iter(|| {
             let mut graph = Graph::new();
-            contents
-                .lines()
-                .map(|s| Edge::from_str(black_box(s)))
-                .filter_map(Result::ok)
+            parse_lines::<Edge>(black_box(&contents))
+                .unwrap()
+                .into_iter()
                 .for_each(|edge| graph.add_edge(black_box(edge)));
 
             let _ = graph.longest_path();
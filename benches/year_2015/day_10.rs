@@ -1,20 +1,25 @@
+use std::fs;
+
 use criterion::{black_box, criterion_group, Criterion};
 
 use advent_of_code::year_2015::day_10::look_and_say;
 
 fn look_and_say_benchmark(c: &mut Criterion) {
-    c.bench_function("year_2015::day_10 - look_and_say 40", |b| {
+    let contents =
+        fs::read_to_string("input/2015/day-10.txt").expect("Failed to read file to string.");
+
+    c.bench_function("year_2015::day_10 - look_and_say file/40", |b| {
         b.iter(|| {
-            let mut input = String::from("1113122113");
+            let mut input = String::from(contents.trim());
             for _ in 0..40 {
                 input = look_and_say(black_box(&input));
             }
         });
     });
 
-    c.bench_function("year_2015::day_10 - look_and_say 50", |b| {
+    c.bench_function("year_2015::day_10 - look_and_say file/50", |b| {
         b.iter(|| {
-            let mut input = String::from("1113122113");
+            let mut input = String::from(contents.trim());
             for _ in 0..50 {
                 input = look_and_say(black_box(&input));
             }
@@ -1,19 +1,27 @@
+use std::fs;
+
 use criterion::{black_box, criterion_group, Criterion};
 
 use advent_of_code::year_2015::day_11::next_password;
 
 fn next_password_benchmark(c: &mut Criterion) {
+    let contents =
+        fs::read_to_string("input/2015/day-11.txt").expect("Failed to read file to string.");
+
     c.bench_function("year_2015::day_11 - next_password abcdefgh", |b| {
         b.iter(|| next_password(black_box("abcdefgh")));
     });
     c.bench_function("year_2015::day_11 - next_password ghijklmn", |b| {
         b.iter(|| next_password(black_box("ghijklmn")));
     });
-    c.bench_function("year_2015::day_11 - next_password cqjxjnds", |b| {
-        b.iter(|| next_password(black_box("cqjxjnds")));
+    c.bench_function("year_2015::day_11 - next_password file", |b| {
+        b.iter(|| next_password(black_box(contents.trim())));
     });
-    c.bench_function("year_2015::day_11 - next_password cqjxxyzz", |b| {
-        b.iter(|| next_password(black_box("cqjxxyzz")));
+    c.bench_function("year_2015::day_11 - next_password file twice", |b| {
+        b.iter(|| {
+            let password = next_password(black_box(contents.trim())).unwrap();
+            next_password(black_box(&password))
+        });
     });
 }
 
@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use criterion::{black_box, criterion_group, Criterion};
 
+use advent_of_code::util::parse_lines;
 use advent_of_code::year_2015::day_13::{SeatingPlan, SeatingPreference};
 
 fn happiest_table_benchmark(c: &mut Criterion) {
@@ -13,10 +14,9 @@ fn happiest_table_benchmark(c: &mut Criterion) {
         b.iter(|| {
             let mut plan = SeatingPlan::new();
 
-            contents
-                .lines()
-                .map(|s| SeatingPreference::from_str(black_box(s)))
-                .filter_map(Result::ok)
+            parse_lines::<SeatingPreference>(black_box(&contents))
+                .unwrap()
+                .into_iter()
                 .for_each(|preference| plan.add_preference(black_box(preference)));
 
             let _ = plan.happiest_table();
@@ -30,10 +30,9 @@ fn happiest_table_benchmark(c: &mut Criterion) {
         b.iter(|| {
             let mut plan = SeatingPlan::new();
 
-            contents
-                .lines()
-                .map(|s| SeatingPreference::from_str(black_box(s)))
-                .filter_map(Result::ok)
+            parse_lines::<SeatingPreference>(black_box(&contents))
+                .unwrap()
+                .into_iter()
                 .for_each(|preference| plan.add_preference(black_box(preference)));
 
             let _ = plan.happiest_table();
@@ -43,10 +42,9 @@ fn happiest_table_benchmark(c: &mut Criterion) {
     c.bench_function("year_2015::day_13 - happiest_table file + you", |b| {
         b.iter(|| {
             let mut plan = SeatingPlan::new();
-            contents
-                .lines()
-                .map(|s| SeatingPreference::from_str(black_box(s)))
-                .filter_map(Result::ok)
+            parse_lines::<SeatingPreference>(black_box(&contents))
+                .unwrap()
+                .into_iter()
                 .for_each(|preference| plan.add_preference(black_box(preference)));
 
             for guest in plan.guests.clone() {
@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use criterion::{black_box, criterion_group, Criterion};
 
+use advent_of_code::util::parse_lines;
 use advent_of_code::year_2015::day_14::{
     distance_winning_reindeer_traveled, points_awarded_winning_reindeer, Reindeer,
 };
@@ -37,11 +38,7 @@ fn distance_winning_reindeer_traveled_benchmark(c: &mut Criterion) {
         "year_2015::day_14 - distance_winning_reindeer_traveled file/2503",
         |b| {
             b.iter(|| {
-                let reindeer: Vec<Reindeer> = contents
-                    .lines()
-                    .map(|s| Reindeer::from_str(black_box(s)))
-                    .filter_map(Result::ok)
-                    .collect();
+                let reindeer: Vec<Reindeer> = parse_lines(black_box(&contents)).unwrap();
 
                 let _ = distance_winning_reindeer_traveled(black_box(&reindeer), black_box(2503));
             });
@@ -79,11 +76,7 @@ fn points_awarded_winning_reindeer_benchmark(c: &mut Criterion) {
         "year_2015::day_14 - points_awarded_winning_reindeer file/2503",
         |b| {
             b.iter(|| {
-                let reindeer: Vec<Reindeer> = contents
-                    .lines()
-                    .map(|s| Reindeer::from_str(black_box(s)))
-                    .filter_map(Result::ok)
-                    .collect();
+                let reindeer: Vec<Reindeer> = parse_lines(black_box(&contents)).unwrap();
 
                 let _ = points_awarded_winning_reindeer(black_box(&reindeer), black_box(2503));
             });
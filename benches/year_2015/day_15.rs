@@ -0,0 +1,43 @@
+use std::fs;
+
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::util::parse_lines;
+use advent_of_code::year_2015::day_15::Recipe;
+
+fn highest_score_benchmark(c: &mut Criterion) {
+    let contents =
+        fs::read_to_string("input/2015/day-15-sample.txt").expect("Failed to read file to string.");
+
+    c.bench_function("year_2015::day_15 - highest_score sample", |b| {
+        b.iter(|| {
+            let ingredients = parse_lines(black_box(&contents)).unwrap();
+            let recipe = Recipe::new(ingredients);
+            let _ = recipe.highest_score(black_box(100));
+        });
+    });
+
+    let contents =
+        fs::read_to_string("input/2015/day-15.txt").expect("Failed to read file to string.");
+
+    c.bench_function("year_2015::day_15 - highest_score file", |b| {
+        b.iter(|| {
+            let ingredients = parse_lines(black_box(&contents)).unwrap();
+            let recipe = Recipe::new(ingredients);
+            let _ = recipe.highest_score(black_box(100));
+        });
+    });
+
+    c.bench_function(
+        "year_2015::day_15 - highest_score_with_calories file",
+        |b| {
+            b.iter(|| {
+                let ingredients = parse_lines(black_box(&contents)).unwrap();
+                let recipe = Recipe::new(ingredients);
+                let _ = recipe.highest_score_with_calories(black_box(100), black_box(500));
+            });
+        },
+    );
+}
+
+criterion_group!(benches, highest_score_benchmark);
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_16::{find_real_sue, find_sue, Sue};
+
+const SUES: [&str; 4] = [
+    "Sue 1: goldfish: 9, cars: 0, samoyeds: 9",
+    "Sue 2: cats: 7, trees: 3, akitas: 0",
+    "Sue 3: cats: 10, pomeranians: 1, perfumes: 1",
+    "Sue 4: children: 3, goldfish: 5, vizslas: 0",
+];
+
+fn find_sue_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_16 - find_sue sample", |b| {
+        b.iter(|| {
+            let sues: Vec<Sue> = SUES
+                .iter()
+                .map(|s| Sue::from_str(black_box(s)).unwrap())
+                .collect();
+            let _ = find_sue(&sues);
+        });
+    });
+}
+
+fn find_real_sue_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_16 - find_real_sue sample", |b| {
+        b.iter(|| {
+            let sues: Vec<Sue> = SUES
+                .iter()
+                .map(|s| Sue::from_str(black_box(s)).unwrap())
+                .collect();
+            let _ = find_real_sue(&sues);
+        });
+    });
+}
+
+criterion_group!(benches, find_sue_benchmark, find_real_sue_benchmark);
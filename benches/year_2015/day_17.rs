@@ -0,0 +1,21 @@
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_17::{combinations, minimum_combinations};
+
+fn combinations_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_17 - combinations sample/25", |b| {
+        b.iter(|| combinations(black_box(&[20, 15, 10, 5, 5]), black_box(25)));
+    });
+}
+
+fn minimum_combinations_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_17 - minimum_combinations sample/25", |b| {
+        b.iter(|| minimum_combinations(black_box(&[20, 15, 10, 5, 5]), black_box(25)));
+    });
+}
+
+criterion_group!(
+    benches,
+    combinations_benchmark,
+    minimum_combinations_benchmark
+);
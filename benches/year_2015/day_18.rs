@@ -0,0 +1,36 @@
+use std::fs;
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_18::{lights_on_after, lights_on_after_stuck, Animation};
+
+fn lights_on_after_benchmark(c: &mut Criterion) {
+    let contents =
+        fs::read_to_string("input/2015/day-18-sample.txt").expect("Failed to read file to string.");
+
+    c.bench_function("year_2015::day_18 - lights_on_after sample/4", |b| {
+        b.iter(|| {
+            let animation = Animation::from_str(black_box(contents.trim())).unwrap();
+            let _ = lights_on_after(&animation, black_box(4));
+        });
+    });
+}
+
+fn lights_on_after_stuck_benchmark(c: &mut Criterion) {
+    let contents =
+        fs::read_to_string("input/2015/day-18-sample.txt").expect("Failed to read file to string.");
+
+    c.bench_function("year_2015::day_18 - lights_on_after_stuck sample/5", |b| {
+        b.iter(|| {
+            let animation = Animation::from_str(black_box(contents.trim())).unwrap();
+            let _ = lights_on_after_stuck(&animation, black_box(5));
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    lights_on_after_benchmark,
+    lights_on_after_stuck_benchmark
+);
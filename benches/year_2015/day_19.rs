@@ -0,0 +1,29 @@
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_19::Machine;
+
+fn calibrate_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_19 - calibrate sample", |b| {
+        b.iter(|| {
+            let machine =
+                Machine::from_str(black_box("H => HO\nH => OH\nO => HH\n\nHOHOHO")).unwrap();
+            let _ = machine.calibrate();
+        });
+    });
+}
+
+fn fewest_steps_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_19 - fewest_steps sample", |b| {
+        b.iter(|| {
+            let machine = Machine::from_str(black_box(
+                "e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOHOHO",
+            ))
+            .unwrap();
+            let _ = machine.fewest_steps();
+        });
+    });
+}
+
+criterion_group!(benches, calibrate_benchmark, fewest_steps_benchmark);
@@ -0,0 +1,24 @@
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_20::{lowest_house_number, lowest_house_number_lazy_elves};
+
+fn lowest_house_number_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_20 - lowest_house_number 100000", |b| {
+        b.iter(|| lowest_house_number(black_box(100_000)));
+    });
+}
+
+fn lowest_house_number_lazy_elves_benchmark(c: &mut Criterion) {
+    c.bench_function(
+        "year_2015::day_20 - lowest_house_number_lazy_elves 100000",
+        |b| {
+            b.iter(|| lowest_house_number_lazy_elves(black_box(100_000)));
+        },
+    );
+}
+
+criterion_group!(
+    benches,
+    lowest_house_number_benchmark,
+    lowest_house_number_lazy_elves_benchmark
+);
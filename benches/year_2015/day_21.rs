@@ -0,0 +1,27 @@
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_21::{least_gold_to_win, most_gold_to_lose, Character};
+
+const BOSS: Character = Character {
+    hit_points: 100,
+    damage: 8,
+    armor: 2,
+};
+
+fn least_gold_to_win_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_21 - least_gold_to_win", |b| {
+        b.iter(|| least_gold_to_win(black_box(&BOSS)));
+    });
+}
+
+fn most_gold_to_lose_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_21 - most_gold_to_lose", |b| {
+        b.iter(|| most_gold_to_lose(black_box(&BOSS)));
+    });
+}
+
+criterion_group!(
+    benches,
+    least_gold_to_win_benchmark,
+    most_gold_to_lose_benchmark
+);
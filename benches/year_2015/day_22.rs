@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_22::{least_mana_to_win, Boss, Difficulty, Wizard};
+
+const BOSS: Boss = Boss {
+    hit_points: 51,
+    damage: 9,
+};
+
+fn least_mana_to_win_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_22 - least_mana_to_win normal", |b| {
+        b.iter(|| {
+            least_mana_to_win(
+                &Wizard::default(),
+                black_box(&BOSS),
+                black_box(Difficulty::Normal),
+            )
+        });
+    });
+    c.bench_function("year_2015::day_22 - least_mana_to_win hard", |b| {
+        b.iter(|| {
+            least_mana_to_win(
+                &Wizard::default(),
+                black_box(&BOSS),
+                black_box(Difficulty::Hard),
+            )
+        });
+    });
+}
+
+criterion_group!(benches, least_mana_to_win_benchmark);
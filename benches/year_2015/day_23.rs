@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_23::{Computer, Instruction};
+
+fn run_benchmark(c: &mut Criterion) {
+    let program: Vec<Instruction> = [
+        "jio a, +8",
+        "inc b",
+        "jie a, +4",
+        "tpl a",
+        "inc a",
+        "jmp +2",
+        "hlf a",
+        "jmp -7",
+    ]
+    .iter()
+    .map(|s| Instruction::from_str(s).unwrap())
+    .collect();
+
+    c.bench_function("year_2015::day_23 - run collatz/27", |b| {
+        b.iter(|| {
+            let mut computer = Computer::new(black_box(27), 0);
+            computer.run(black_box(&program));
+        });
+    });
+}
+
+criterion_group!(benches, run_benchmark);
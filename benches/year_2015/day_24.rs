@@ -0,0 +1,16 @@
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_24::ideal_entanglement;
+
+const PACKAGES: [u64; 10] = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+
+fn ideal_entanglement_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_24 - ideal_entanglement sample/3", |b| {
+        b.iter(|| ideal_entanglement(black_box(&PACKAGES), black_box(3)));
+    });
+    c.bench_function("year_2015::day_24 - ideal_entanglement sample/4", |b| {
+        b.iter(|| ideal_entanglement(black_box(&PACKAGES), black_box(4)));
+    });
+}
+
+criterion_group!(benches, ideal_entanglement_benchmark);
@@ -0,0 +1,16 @@
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2015::day_25::{code_at, Position};
+
+fn code_at_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2015::day_25 - code_at 2947/3029", |b| {
+        b.iter(|| {
+            code_at(black_box(Position {
+                row: 2947,
+                column: 3029,
+            }))
+        });
+    });
+}
+
+criterion_group!(benches, code_at_benchmark);
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, Criterion};
+
+use advent_of_code::year_2016::day_01::{distance_to_destination, distance_to_first_revisit, Step};
+
+fn distance_to_destination_benchmark(c: &mut Criterion) {
+    c.bench_function("year_2016::day_01 - distance_to_destination sample", |b| {
+        b.iter(|| {
+            let steps: Vec<Step> = black_box("R5, L5, R5, R3")
+                .split(", ")
+                .map(|s| Step::from_str(s).unwrap())
+                .collect();
+            let _ = distance_to_destination(&steps);
+        });
+    });
+}
+
+fn distance_to_first_revisit_benchmark(c: &mut Criterion) {
+    c.bench_function(
+        "year_2016::day_01 - distance_to_first_revisit sample",
+        |b| {
+            b.iter(|| {
+                let steps: Vec<Step> = black_box("R8, R4, R4, R8")
+                    .split(", ")
+                    .map(|s| Step::from_str(s).unwrap())
+                    .collect();
+                let _ = distance_to_first_revisit(&steps);
+            });
+        },
+    );
+}
+
+criterion_group!(
+    benches,
+    distance_to_destination_benchmark,
+    distance_to_first_revisit_benchmark
+);
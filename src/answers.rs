@@ -0,0 +1,165 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hashbrown::HashMap;
+use serde::Serialize;
+
+use crate::solution::Part;
+
+/// Represents the known answers to the puzzles of a year.
+///
+/// Answers are written in a subset of TOML, with a table for each day and a key for each part.
+/// Values may be strings or integers, and lines starting with `#` are comments.
+///
+/// ```toml
+/// [day-01]
+/// part-one = 280
+/// part-two = "1797"
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AnswerKey {
+    answers: HashMap<(u8, Part), String>,
+}
+
+impl AnswerKey {
+    /// Constructs a new empty `AnswerKey`.
+    #[must_use]
+    pub fn new() -> AnswerKey {
+        AnswerKey {
+            answers: HashMap::new(),
+        }
+    }
+
+    /// Returns the path of the answers file for the provided year inside the provided directory, `<dir>/<year>.toml`.
+    #[must_use]
+    pub fn path(dir: &Path, year: u16) -> PathBuf {
+        dir.join(format!("{}.toml", year))
+    }
+
+    /// Returns the known answer to the provided part of the puzzle on the provided day.
+    #[must_use]
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Sets the known answer to the provided part of the puzzle on the provided day.
+    pub fn insert(&mut self, day: u8, part: Part, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    /// Compares an answer to the known answer for the same part of the puzzle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::answers::{AnswerKey, Status};
+    /// use advent_of_code::solution::Part;
+    ///
+    /// let key = AnswerKey::from_str("[day-01]\npart-one = 280").unwrap();
+    /// assert_eq!(key.check(1, Part::One, "280"), Status::Pass);
+    /// assert_eq!(key.check(1, Part::One, "281"), Status::Fail);
+    /// assert_eq!(key.check(1, Part::Two, "1797"), Status::Unknown);
+    /// ```
+    #[must_use]
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Status {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Unknown,
+        }
+    }
+}
+
+impl FromStr for AnswerKey {
+    type Err = ParseAnswersError;
+
+    fn from_str(s: &str) -> Result<AnswerKey, ParseAnswersError> {
+        let mut key = AnswerKey::new();
+        let mut day = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let error = |message: &str| ParseAnswersError {
+                line_number: i + 1,
+                message: String::from(message),
+            };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let table = table
+                    .strip_suffix(']')
+                    .ok_or_else(|| error("table is missing a closing bracket"))?;
+                let number = table
+                    .trim()
+                    .strip_prefix("day-")
+                    .and_then(|number| number.parse().ok())
+                    .ok_or_else(|| error("table was not in format '[day-<DAY>]'"))?;
+                day = Some(number);
+                continue;
+            }
+
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("line was not in format '<KEY> = <VALUE>'"))?;
+            let part = match name.trim() {
+                "part-one" => Part::One,
+                "part-two" => Part::Two,
+                _ => return Err(error("key was not 'part-one' or 'part-two'")),
+            };
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(value) => value
+                    .strip_suffix('"')
+                    .ok_or_else(|| error("string is missing a closing quote"))?,
+                None if value.parse::<i64>().is_ok() => value,
+                None => return Err(error("value was not a string or an integer")),
+            };
+            let day = day.ok_or_else(|| error("answer is not inside a '[day-<DAY>]' table"))?;
+
+            key.insert(day, part, String::from(value));
+        }
+        Ok(key)
+    }
+}
+
+/// Represents the result of comparing an answer to the known answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => "PASS".fmt(f),
+            Status::Fail => "FAIL".fmt(f),
+            Status::Unknown => "UNKNOWN".fmt(f),
+        }
+    }
+}
+
+/// Error type used when parsing an answer key from a `str`.
+#[derive(Debug)]
+pub struct ParseAnswersError {
+    line_number: usize,
+    message: String,
+}
+
+impl Error for ParseAnswersError {}
+
+impl Display for ParseAnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line_number, self.message)
+    }
+}
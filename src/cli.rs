@@ -0,0 +1,410 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fs, io};
+
+use crate::report::Format;
+use crate::solution::{Part, Puzzle};
+
+/// The help message printed by the binary.
+pub const USAGE: &str = "Usage: advent-of-code [OPTIONS]
+
+Options:
+    -y, --year <YEARS>      Years of the events to run, e.g. 2015, 2015..=2016, or all [default: all]
+    -d, --day <DAYS>        Days of the puzzles to run, e.g. 6, 1..=10, 1..5, 1,3,5, or all [default: all]
+    -p, --part <PARTS>      Parts of the puzzles to run, e.g. 1, 2, or all [default: all]
+    -i, --input <PATH>      Input file of the selected puzzle, or - to read from stdin,
+                            when several puzzles are selected, those without an input file are skipped
+    -k, --key <KEY>         Input of the selected puzzle provided as text, e.g. the secret key of 2015 day 4
+    -f, --format <FORMAT>   Format of the answers, either text or json [default: text]
+    -t, --timings           Print a table of the time taken to parse the input and solve each part,
+                            not available with the json format, which always includes the timings
+    --slowest <COUNT>       Print the timings of only the slowest puzzles, slowest first,
+                            not available with the json format
+    -l, --lenient           Skip the lines of the input that cannot be parsed instead of failing,
+                            and print each skipped line with its line number to stderr
+    -c, --check             Compare the answers to the known answers and report PASS, FAIL or UNKNOWN,
+                            the answers to an input provided with --input or --key are UNKNOWN
+    --answers-dir <DIR>     Directory containing the known answers, as <DIR>/<YEAR>.toml [default: the input directory]
+    --input-dir <DIR>       Directory containing the input files, as <DIR>/<YEAR>/day-<DAY>.txt [default: input]
+    -h, --help              Print this help message";
+
+/// Represents a selection of numbers, such as years, days or parts.
+///
+/// A selection is written as `all`, a single number `6`, an inclusive range `1..=10`, an exclusive range `1..5`,
+/// or a comma separated list of any of these `1,3,10..=12`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Selection {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl Selection {
+    /// Constructs a new `Selection` containing every number.
+    #[must_use]
+    pub fn all() -> Selection {
+        Selection { ranges: Vec::new() }
+    }
+
+    /// Returns `true` if the selection contains the provided number; `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::cli::Selection;
+    ///
+    /// let days = Selection::from_str("1..=10").unwrap();
+    /// assert!(days.contains(1));
+    /// assert!(days.contains(10));
+    /// assert!(!days.contains(11));
+    ///
+    /// let days = Selection::from_str("1..5,7").unwrap();
+    /// assert!(days.contains(4));
+    /// assert!(!days.contains(5));
+    /// assert!(days.contains(7));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, number: u32) -> bool {
+        self.ranges.is_empty()
+            || self
+                .ranges
+                .iter()
+                .any(|&(start, end)| start <= number && number <= end)
+    }
+}
+
+impl FromStr for Selection {
+    type Err = ArgumentError;
+
+    fn from_str(s: &str) -> Result<Selection, ArgumentError> {
+        if s == "all" {
+            return Ok(Selection::all());
+        }
+
+        let mut ranges = Vec::new();
+        for item in s.split(',') {
+            let range = if let Some((start, end)) = item.split_once("..=") {
+                (parse_number(start)?, parse_number(end)?)
+            } else if let Some((start, end)) = item.split_once("..") {
+                let end = parse_number(end)?;
+                if end == 0 {
+                    return Err(ArgumentError::new(format!("range '{}' is empty", item)));
+                }
+                (parse_number(start)?, end - 1)
+            } else {
+                let number = parse_number(item)?;
+                (number, number)
+            };
+
+            if range.0 > range.1 {
+                return Err(ArgumentError::new(format!("range '{}' is empty", item)));
+            }
+            ranges.push(range);
+        }
+        Ok(Selection { ranges })
+    }
+}
+
+fn parse_number(s: &str) -> Result<u32, ArgumentError> {
+    s.parse()
+        .map_err(|_| ArgumentError::new(format!("'{}' is not a number", s)))
+}
+
+/// Represents where the input of a puzzle is read from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    Text(String),
+}
+
+impl InputSource {
+    /// Reads the whole input to a `String`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error naming the source if it cannot be read.
+    pub fn read(&self) -> Result<String, InputError> {
+        let mut contents = String::new();
+        let result = match self {
+            InputSource::Stdin => io::stdin().read_to_string(&mut contents),
+            InputSource::File(path) => {
+                fs::File::open(path).and_then(|mut file| file.read_to_string(&mut contents))
+            }
+            InputSource::Text(text) => return Ok(text.clone()),
+        };
+
+        match result {
+            Ok(_) => Ok(contents),
+            Err(source) => Err(InputError {
+                input: self.clone(),
+                source,
+            }),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => "stdin".fmt(f),
+            InputSource::File(path) => path.display().fmt(f),
+            InputSource::Text(_) => "key".fmt(f),
+        }
+    }
+}
+
+/// Represents the options the binary was run with.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Options {
+    pub years: Selection,
+    pub days: Selection,
+    pub parts: Selection,
+    pub input: Option<InputSource>,
+    pub input_dir: PathBuf,
+    pub format: Format,
+    pub timings: bool,
+    pub slowest: Option<usize>,
+    pub lenient: bool,
+    pub check: bool,
+    pub answers_dir: Option<PathBuf>,
+    pub help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            years: Selection::all(),
+            days: Selection::all(),
+            parts: Selection::all(),
+            input: None,
+            input_dir: PathBuf::from("input"),
+            format: Format::Text,
+            timings: false,
+            slowest: None,
+            lenient: false,
+            check: false,
+            answers_dir: None,
+            help: false,
+        }
+    }
+}
+
+impl Options {
+    /// Parses the provided command-line arguments, not including the name of the binary.
+    ///
+    /// Values can be provided as the next argument `--day 6` or after an equals sign `--day=6`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if an argument is unknown, is missing its value, or has a value that is not a valid selection.
+    /// Will also return an error if `--timings` or `--slowest` is combined with the json format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::cli::Options;
+    ///
+    /// let options = Options::parse(vec!["--year", "2015", "--day", "1..=10", "--part=2"]).unwrap();
+    /// assert!(options.days.contains(10));
+    /// assert!(!options.parts.contains(1));
+    /// ```
+    pub fn parse<I, S>(args: I) -> Result<Options, ArgumentError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut options = Options::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with('-') => (name, Some(String::from(value))),
+                _ => (arg, None),
+            };
+
+            let mut value = || match inline_value.clone() {
+                Some(value) => Ok(value),
+                None => args
+                    .next()
+                    .map(|value| String::from(value.as_ref()))
+                    .ok_or_else(|| {
+                        ArgumentError::new(format!("argument '{}' requires a value", name))
+                    }),
+            };
+
+            match name {
+                "-h" | "--help" => options.help = true,
+                "-t" | "--timings" => options.timings = true,
+                "-l" | "--lenient" => options.lenient = true,
+                "-c" | "--check" => options.check = true,
+                "-y" | "--year" => options.years = Selection::from_str(&value()?)?,
+                "-d" | "--day" => options.days = Selection::from_str(&value()?)?,
+                "-p" | "--part" => options.parts = Selection::from_str(&value()?)?,
+                "-i" | "--input" => {
+                    let value = value()?;
+                    options.input = if value == "-" {
+                        Some(InputSource::Stdin)
+                    } else {
+                        Some(InputSource::File(PathBuf::from(value)))
+                    };
+                }
+                "-k" | "--key" => options.input = Some(InputSource::Text(value()?)),
+                "--input-dir" => options.input_dir = PathBuf::from(value()?),
+                "--answers-dir" => options.answers_dir = Some(PathBuf::from(value()?)),
+                "--slowest" => {
+                    let value = value()?;
+                    options.timings = true;
+                    options.slowest =
+                        Some(value.parse().map_err(|_| {
+                            ArgumentError::new(format!("'{}' is not a number", value))
+                        })?);
+                }
+                "-f" | "--format" => {
+                    let value = value()?;
+                    options.format = Format::from_str(&value).map_err(|err| {
+                        ArgumentError::new(format!("invalid format '{}': {}", value, err))
+                    })?;
+                }
+                _ => return Err(ArgumentError::new(format!("unknown argument '{}'", arg))),
+            }
+        }
+
+        if options
+            .parts
+            .ranges
+            .iter()
+            .any(|&(start, end)| start == 0 || end > 2)
+        {
+            return Err(ArgumentError::new(String::from(
+                "puzzles only have parts 1 and 2",
+            )));
+        }
+
+        if options.timings && options.format == Format::Json {
+            return Err(ArgumentError::new(String::from(
+                "the timings table cannot be printed with the json format, which already includes the timings",
+            )));
+        }
+
+        Ok(options)
+    }
+
+    /// Returns `true` if the provided puzzle was selected; `false` otherwise.
+    #[must_use]
+    pub fn selects(&self, puzzle: &Puzzle) -> bool {
+        self.years.contains(u32::from(puzzle.year)) && self.days.contains(u32::from(puzzle.day))
+    }
+
+    /// Returns the source to read the input of the provided puzzle from.
+    ///
+    /// If no input was provided, the input is read from `<input_dir>/<year>/day-<day>.txt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use advent_of_code::cli::{InputSource, Options};
+    ///
+    /// let options = Options::parse(vec!["--input-dir", "inputs"]).unwrap();
+    /// let puzzle = advent_of_code::puzzle(2015, 6).unwrap();
+    /// let path = PathBuf::from("inputs").join("2015").join("day-06.txt");
+    /// assert_eq!(options.input_source(puzzle), InputSource::File(path));
+    /// ```
+    #[must_use]
+    pub fn input_source(&self, puzzle: &Puzzle) -> InputSource {
+        match &self.input {
+            Some(input) => input.clone(),
+            None => InputSource::File(puzzle.input_path(&self.input_dir)),
+        }
+    }
+
+    /// Returns the directory containing the known answers.
+    ///
+    /// If no directory was provided, the answers are read from next to the inputs, as `<input_dir>/<year>.toml`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use advent_of_code::cli::Options;
+    ///
+    /// let options = Options::parse(vec!["--input-dir", "inputs"]).unwrap();
+    /// assert_eq!(options.answers_dir(), Path::new("inputs"));
+    ///
+    /// let options = Options::parse(vec!["--input-dir", "inputs", "--answers-dir", "answers"]).unwrap();
+    /// assert_eq!(options.answers_dir(), Path::new("answers"));
+    /// ```
+    #[must_use]
+    pub fn answers_dir(&self) -> &Path {
+        self.answers_dir.as_deref().unwrap_or(&self.input_dir)
+    }
+
+    /// Returns the selected parts, in order.
+    #[must_use]
+    pub fn selected_parts(&self) -> Vec<Part> {
+        Part::ALL
+            .iter()
+            .copied()
+            .filter(|part| self.parts.contains(u32::from(part.number())))
+            .collect()
+    }
+}
+
+/// Error type used when parsing command-line arguments.
+#[derive(Debug)]
+pub struct ArgumentError {
+    message: String,
+}
+
+impl ArgumentError {
+    fn new(message: String) -> ArgumentError {
+        ArgumentError { message }
+    }
+}
+
+impl Error for ArgumentError {}
+
+impl Display for ArgumentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+/// Error type used when the input of a puzzle cannot be read.
+#[derive(Debug)]
+pub struct InputError {
+    input: InputSource,
+    source: io::Error,
+}
+
+impl InputError {
+    /// Returns the source of the input that could not be read.
+    #[must_use]
+    pub fn input(&self) -> &InputSource {
+        &self.input
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to read input from '{}': {}",
+            self.input, self.source
+        )
+    }
+}
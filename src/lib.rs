@@ -2,6 +2,51 @@
 This crate provides a library for solving Advent of Code puzzles.
 */
 
-mod util;
+use crate::solution::Puzzle;
+
+/// This module provides the known answers used to check the answers of puzzles.
+pub mod answers;
+/// This module provides the command-line options of the binary.
+pub mod cli;
+/// This module provides the formats the answers of puzzles are written in.
+pub mod report;
+/// This module provides the common interface implemented by every puzzle solution.
+pub mod solution;
+/// This module provides the error type and helpers shared by the puzzle input parsers.
+pub mod util;
 /// This module provides the solutions to puzzles for 2015.
 pub mod year_2015;
+/// This module provides the solutions to puzzles for 2016.
+pub mod year_2016;
+
+/// The registered puzzles of every year.
+static YEARS: [&[Puzzle]; 2] = [year_2015::PUZZLES, year_2016::PUZZLES];
+
+/// Returns an iterator over every registered puzzle, ordered by year and day.
+///
+/// # Examples
+///
+/// ```
+/// let count = advent_of_code::puzzles().count();
+/// assert!(count > 0);
+/// ```
+pub fn puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    YEARS.iter().flat_map(|puzzles| puzzles.iter())
+}
+
+/// Returns the registered puzzle for the provided year and day.
+/// If no puzzle is registered `None` is returned.
+///
+/// # Examples
+///
+/// ```
+/// let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+/// assert_eq!(puzzle.title, "Not Quite Lisp");
+///
+/// let puzzle = advent_of_code::puzzle(2015, 26);
+/// assert!(puzzle.is_none());
+/// ```
+#[must_use]
+pub fn puzzle(year: u16, day: u8) -> Option<&'static Puzzle> {
+    puzzles().find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
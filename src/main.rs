@@ -1,322 +1,181 @@
-use std::fs;
+use std::io::{ErrorKind, Write};
 use std::str::FromStr;
+use std::{env, fs, io, process};
 
-use advent_of_code::year_2015::day_01::{floor, position_to_floor};
-use advent_of_code::year_2015::day_02::Present;
-use advent_of_code::year_2015::day_03::InfiniteGrid;
-use advent_of_code::year_2015::day_04::find_number;
-use advent_of_code::year_2015::day_05::{is_nice_word, is_nice_word2};
-use advent_of_code::year_2015::day_06::{AdjustableBulb, LightGrid, LightInstruction, SimpleBulb};
-use advent_of_code::year_2015::day_07::{Circuit, CircuitInstruction};
-use advent_of_code::year_2015::day_08::{escape_string, reformat_string};
-use advent_of_code::year_2015::day_09::{Edge, Graph};
-use advent_of_code::year_2015::day_10::look_and_say;
-use advent_of_code::year_2015::day_11::next_password;
-use advent_of_code::year_2015::day_12::{sum_numbers_in_str, sum_value};
-use advent_of_code::year_2015::day_13::{SeatingPlan, SeatingPreference};
-use advent_of_code::year_2015::day_14::{
-    distance_winning_reindeer_traveled, points_awarded_winning_reindeer, Reindeer,
-};
+use hashbrown::HashMap;
 
-fn main() {
-    run_2015_01();
-    run_2015_02();
-    run_2015_03();
-    run_2015_04();
-    run_2015_05();
-    run_2015_06();
-    run_2015_07();
-    run_2015_08();
-    run_2015_09();
-    run_2015_10();
-    run_2015_11();
-    run_2015_12();
-    run_2015_13();
-    run_2015_14();
-}
-
-/// Displays the solutions to Year 2015 Day 1: Not Quite Lisp.
-fn run_2015_01() {
-    println!("Advent of Code - Year 2015 Day 1: Not Quite Lisp");
-    let contents = fs::read("input/2015/day-01.txt").expect("Failed to read file.");
-
-    let f = floor(&contents);
-    println!("The instructions take Santa to floor {}.", f);
-
-    let p = position_to_floor(&contents, -1).unwrap();
-    println!("The Santa enters the basement at position {}.", p);
-}
-
-/// Displays the solutions to Year 2015 Day 2: I Was Told There Would Be No Math.
-fn run_2015_02() {
-    println!("Advent of Code - Year 2015 Day 2: I Was Told There Would Be No Math");
-    let contents =
-        fs::read_to_string("input/2015/day-02.txt").expect("Failed to read file to string.");
-
-    let presents: Vec<Present> = contents
-        .lines()
-        .map(|s| Present::from_str(s))
-        .filter_map(Result::ok)
-        .collect();
-
-    let wrapping_paper_needed: u32 = presents.iter().map(Present::wrapping_paper_needed).sum();
-
-    println!(
-        "The elves need {} square feet of wrapping paper.",
-        wrapping_paper_needed
-    );
-
-    let ribbon_needed: u32 = presents.iter().map(Present::ribbon_needed).sum();
-
-    println!("The elves need {} feet of ribbon.", ribbon_needed);
-}
-
-/// Displays the solutions to Year 2015 Day 3: Perfectly Spherical Houses in a Vacuum.
-fn run_2015_03() {
-    println!("Advent of Code - Year 2015 Day 3: Perfectly Spherical Houses in a Vacuum");
-    let contents =
-        fs::read_to_string("input/2015/day-03.txt").expect("Failed to read file to string.");
-
-    let mut grid = InfiniteGrid::new(1);
-
-    contents.chars().for_each(|c| grid.move_position(c));
-
-    let visited = grid.visited();
-    println!("Santa visited {} houses at least once.", visited);
-
-    let mut grid = InfiniteGrid::new(2);
-
-    contents.chars().for_each(|c| grid.move_position(c));
-
-    let visited = grid.visited();
-    println!(
-        "Santa and Robo-Santa visited {} houses at least once.",
-        visited
-    );
-}
-
-/// Displays the solutions to Year 2015 Day 4: The Ideal Stocking Stuffer.
-fn run_2015_04() {
-    println!("Advent of Code - Year 2015 Day 4: The Ideal Stocking Stuffer");
-    let key = "iwrupvqb";
-
-    let first_number = find_number(key.as_bytes(), 5).unwrap();
-    println!(
-        "The secret key is {}{} for an MD5 hash with five leading zeroes.",
-        key, first_number
-    );
-
-    let second_number = find_number(key.as_bytes(), 6).unwrap();
-    println!(
-        "The secret key is {}{} for an MD5 hash with six leading zeroes.",
-        key, second_number
-    );
-}
-
-/// Displays the solutions to Year 2015 Day 5: Doesn't He Have Intern-Elves For This?
-fn run_2015_05() {
-    println!("Advent of Code - Year 2015 Day 5: Doesn't He Have Intern-Elves For This?");
-    let contents =
-        fs::read_to_string("input/2015/day-05.txt").expect("Failed to read file to string.");
-
-    let count = contents.lines().filter(|s| is_nice_word(s)).count();
-    println!(
-        "There are {} nice words with the first set of rules.",
-        count
-    );
-
-    let count = contents.lines().filter(|s| is_nice_word2(s)).count();
-    println!(
-        "There are {} nice words with the second set of rules.",
-        count
-    );
-}
-
-/// Displays the solutions to Year 2015 Day 6: Probably a Fire Hazard.
-fn run_2015_06() {
-    println!("Advent of Code - Year 2015 Day 6: Probably a Fire Hazard");
-    let contents =
-        fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
-
-    let mut grid = LightGrid::<SimpleBulb>::new();
-    contents
-        .lines()
-        .map(|s| LightInstruction::from_str(s))
-        .filter_map(Result::ok)
-        .for_each(|instruction| grid.apply_operation(&instruction));
-    let count = grid.total_brightness();
-    println!("There are {} lights lit.", count);
-
-    let mut grid = LightGrid::<AdjustableBulb>::new();
-    contents
-        .lines()
-        .map(|s| LightInstruction::from_str(s))
-        .filter_map(Result::ok)
-        .for_each(|instruction| grid.apply_operation(&instruction));
-    let brightness = grid.total_brightness();
-    println!("The total brightness is {}.", brightness);
-}
-
-fn run_2015_07() {
-    println!("Advent of Code 2015 - Day 7");
-    let contents =
-        fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
-
-    let mut circuit = Circuit::new();
-    contents
-        .lines()
-        .map(|s| CircuitInstruction::from_str(s))
-        .filter_map(Result::ok)
-        .for_each(|instruction| circuit.add_instruction(instruction));
-    circuit.resolve();
+use advent_of_code::answers::{AnswerKey, Status};
+use advent_of_code::cli::{InputSource, Options, USAGE};
+use advent_of_code::report::{slowest, write_records, write_timings, Format, Record, Timing};
+use advent_of_code::solution::{Part, Puzzle, SolutionResult, Solved};
 
-    let signal_a = circuit.signal("a").unwrap();
-    println!("The signal {} is provided to wire 'a'.", signal_a);
-
-    circuit.reset();
-    if let Ok(instruction) = CircuitInstruction::from_str("16076 -> b") {
-        circuit.add_instruction(instruction);
+fn main() {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
     };
-    circuit.resolve();
-
-    let new_signal_a = circuit.signal("a").unwrap();
-    println!("The signal {} is provided to wire 'a'.", new_signal_a);
-}
-
-fn run_2015_08() {
-    println!("Advent of Code 2015 - Day 8");
-    let contents =
-        fs::read_to_string("input/2015/day-08.txt").expect("Failed to read file to string.");
-
-    let before: usize = contents.lines().map(str::len).sum();
-    let after_reformat: usize = contents.lines().map(|s| reformat_string(s).len()).sum();
-
-    println!("The difference between the string literals characters and the string value characters in memory is {} characters.", before - after_reformat);
-
-    let after_escape: usize = contents.lines().map(|s| escape_string(s).len()).sum();
-
-    println!("The difference between the newly encoded string characters and the string literals characters is {} characters.", after_escape - before);
-}
-
-fn run_2015_09() {
-    println!("Advent of Code 2015 - Day 9");
-    let contents =
-        fs::read_to_string("input/2015/day-09.txt").expect("Failed to read file to string.");
-
-    let mut graph = Graph::new();
-    contents
-        .lines()
-        .map(|s| Edge::from_str(s))
-        .filter_map(Result::ok)
-        .for_each(|edge| graph.add_edge(edge));
 
-    let min = graph.shortest_path();
-    println!("The distance of the shortest route is {}.", min);
-
-    let max = graph.longest_path();
-    println!("The distance of the longest route is {}.", max);
-}
+    if options.help {
+        println!("{}", USAGE);
+        return;
+    }
 
-fn run_2015_10() {
-    println!("Advent of Code 2015 - Day 10");
+    let puzzles: Vec<_> = advent_of_code::puzzles()
+        .filter(|puzzle| options.selects(puzzle))
+        .collect();
 
-    let mut input = String::from("1113122113");
-    for _ in 0..40 {
-        input = look_and_say(&input);
+    if puzzles.is_empty() {
+        eprintln!("error: no puzzles match the provided year and day");
+        process::exit(1);
     }
 
-    let length_after_40 = input.len();
-    println!("The length of the result is {}.", length_after_40);
-
-    let mut input = String::from("1113122113");
-    for _ in 0..50 {
-        input = look_and_say(&input);
+    if options.input.is_some() && puzzles.len() > 1 {
+        eprintln!("error: an input can only be provided when a single puzzle is selected");
+        process::exit(2);
     }
 
-    let length_after_50 = input.len();
-    println!("The length of the new result is {}.", length_after_50);
-}
+    let several = puzzles.len() > 1;
+    let parts = options.selected_parts();
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let mut failed = false;
+    let mut ran = false;
+    let mut timings = Vec::new();
+    let mut keys: HashMap<u16, AnswerKey> = HashMap::new();
+    let no_key = AnswerKey::new();
+    let mut statuses = Vec::new();
+    for puzzle in puzzles {
+        // A missing input file is only an error when the puzzle was selected on its own
+        let input = options.input_source(puzzle);
+        if let InputSource::File(path) = &input {
+            if several && options.input.is_none() && !path.exists() {
+                eprintln!(
+                    "warning: skipping Year {} Day {}, no input in '{}'",
+                    puzzle.year,
+                    puzzle.day,
+                    path.display()
+                );
+                continue;
+            }
+        }
+        ran = true;
+
+        let mut records = match input.read() {
+            Ok(contents) => match solve(&options, puzzle, &contents, &parts) {
+                Ok(solved) => {
+                    timings.push(Timing::new(puzzle, &solved));
+                    Record::answers(puzzle, &solved)
+                }
+                Err(err) => parts
+                    .iter()
+                    .map(|&part| Record::error(puzzle, part, &err))
+                    .collect(),
+            },
+            Err(err) => parts
+                .iter()
+                .map(|&part| Record::error(puzzle, part, &err))
+                .collect(),
+        };
+
+        if options.check {
+            // The known answers are for the input files, so the answers to any other input are unknown
+            let key = if options.input.is_some() {
+                &no_key
+            } else {
+                keys.entry(puzzle.year)
+                    .or_insert_with(|| read_answer_key(&options, puzzle.year))
+            };
+            records.iter_mut().for_each(|record| record.check(key));
+            statuses.extend(records.iter().filter_map(|record| record.status));
+        }
+
+        failed |= records.iter().any(Record::is_error);
+        write_records(&mut stdout, options.format, puzzle, &records)
+            .and_then(|_| stdout.flush())
+            .expect("Failed to write to stdout.");
+    }
 
-fn run_2015_11() {
-    println!("Advent of Code 2015 - Day 11");
-    let new_password = next_password("cqjxjnds").unwrap();
-    println!("His next password should be {}.", new_password);
+    if !ran {
+        eprintln!("error: none of the selected puzzles have an input file");
+        process::exit(1);
+    }
 
-    let new_password = next_password("cqjxxyzz").unwrap();
-    println!("The next one is {}.", new_password);
-}
+    if options.check && options.format == Format::Text {
+        let count = |status| statuses.iter().filter(|&&s| s == status).count();
+        writeln!(
+            stdout,
+            "\nChecked {} answers: {} passed, {} failed, {} unknown",
+            statuses.len(),
+            count(Status::Pass),
+            count(Status::Fail),
+            count(Status::Unknown)
+        )
+        .expect("Failed to write to stdout.");
+    }
 
-fn run_2015_12() {
-    println!("Advent of Code 2015 - Day 12");
-    let contents =
-        fs::read_to_string("input/2015/day-12.txt").expect("Failed to read file to string.");
+    if options.timings {
+        if let Some(count) = options.slowest {
+            slowest(&mut timings, count);
+        }
 
-    let number_sum = sum_numbers_in_str(&contents);
-    println!(
-        "The sum of all the numbers in the document is {}.",
-        number_sum
-    );
+        writeln!(stdout)
+            .and_then(|_| write_timings(&mut stdout, &timings))
+            .expect("Failed to write to stdout.");
+    }
 
-    let value = serde_json::from_str(&contents).unwrap();
-    let value_sum = sum_value(&value);
-    println!(
-        "The sum of the numbers without red objects is {}.",
-        value_sum
-    );
+    if failed {
+        process::exit(1);
+    }
 }
 
-fn run_2015_13() {
-    println!("Advent of Code 2015 - Day 13");
-    let contents =
-        fs::read_to_string("input/2015/day-13.txt").expect("Failed to read file to string.");
-
-    let mut plan = SeatingPlan::new();
-    contents
-        .lines()
-        .map(|s| SeatingPreference::from_str(s))
-        .filter_map(Result::ok)
-        .for_each(|preference| plan.add_preference(preference));
-
-    let max = plan.happiest_table();
-    println!(
-        "The total change in happiness for the optimal seating arrangement is {}.",
-        max
-    );
-
-    for guest in plan.guests.clone() {
-        let s = format!(
-            "You would gain 0 happiness units by sitting next to {}.",
-            guest
-        );
-        let preference = SeatingPreference::from_str(&s).unwrap();
-        plan.add_preference(preference);
+/// Solves the selected parts of the puzzle, parsing the input strictly or leniently as selected.
+///
+/// Each line skipped by a lenient parse is printed to stderr with its line number.
+fn solve(
+    options: &Options,
+    puzzle: &Puzzle,
+    contents: &str,
+    parts: &[Part],
+) -> SolutionResult<Solved> {
+    if !options.lenient {
+        return puzzle.solve_parts(contents, parts);
+    }
 
-        let s = format!(
-            "{} would gain 0 happiness units by sitting next to You.",
-            guest
+    let solved = puzzle.solve_parts_lenient(contents, parts)?;
+    for err in &solved.rejected {
+        eprintln!(
+            "warning: Year {} Day {}: skipped {}",
+            puzzle.year, puzzle.day, err
         );
-        let preference = SeatingPreference::from_str(&s).unwrap();
-        plan.add_preference(preference);
     }
-
-    let new_max = plan.happiest_table();
-    println!("The total change in happiness for the optimal seating arrangement that actually includes yourself is {}", new_max);
+    Ok(solved)
 }
 
-fn run_2015_14() {
-    println!("Advent of Code 2015 - Day 14");
-    let contents =
-        fs::read_to_string("input/2015/day-14.txt").expect("Failed to read file to string.");
-
-    let reindeer: Vec<Reindeer> = contents
-        .lines()
-        .map(|s| Reindeer::from_str(s))
-        .filter_map(Result::ok)
-        .collect();
-
-    let max_distance = distance_winning_reindeer_traveled(&reindeer, 2503);
-    println!("The winning reindeer has travelled {} km.", max_distance);
+/// Reads the known answers for the provided year.
+///
+/// A missing answers file is treated as having no known answers, so every answer is reported as unknown.
+fn read_answer_key(options: &Options, year: u16) -> AnswerKey {
+    let path = AnswerKey::path(options.answers_dir(), year);
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            eprintln!("warning: no known answers in '{}'", path.display());
+            return AnswerKey::new();
+        }
+        Err(err) => {
+            eprintln!("error: failed to read '{}': {}", path.display(), err);
+            process::exit(1);
+        }
+    };
 
-    let max_points = points_awarded_winning_reindeer(&reindeer, 2503);
-    println!("The winning reindeer has {} points.", max_points);
+    match AnswerKey::from_str(&contents) {
+        Ok(key) => key,
+        Err(err) => {
+            eprintln!("error: failed to parse '{}': {}", path.display(), err);
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,340 @@
+use std::cmp::Reverse;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use crate::answers::{AnswerKey, Status};
+use crate::solution::{Part, Puzzle, Solved};
+
+/// Represents the formats the answers can be written in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// Human readable text, one line per answer under a heading for each puzzle.
+    #[default]
+    Text,
+    /// JSON Lines, one object per answer.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Format, ParseFormatError> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(ParseFormatError { _priv: () }),
+        }
+    }
+}
+
+/// Error type used when parsing a format from a `str`.
+#[derive(Debug)]
+pub struct ParseFormatError {
+    pub(super) _priv: (),
+}
+
+impl Error for ParseFormatError {}
+
+impl Display for ParseFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        "provided string was not 'text' or 'json'".fmt(f)
+    }
+}
+
+/// Represents the outcome of solving one part of a puzzle.
+///
+/// Either `answer` or `error` is present.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    #[serde(serialize_with = "serialize_part")]
+    pub part: Part,
+    pub title: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The result of comparing the answer to the known answer, if it was checked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    /// The known answer, if the answer was checked and did not match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// The time taken to parse the input of the puzzle in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_ms: Option<f64>,
+    /// The time taken to solve the part in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_ms: Option<f64>,
+}
+
+impl Record {
+    /// Constructs a new `Record` for each part of the solved puzzle.
+    ///
+    /// A part with no answer gets an error record, without affecting the records of the other parts.
+    #[must_use]
+    pub fn answers(puzzle: &Puzzle, solved: &Solved) -> Vec<Record> {
+        solved
+            .answers
+            .iter()
+            .map(|answer| match &answer.value {
+                Ok(value) => Record {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    part: answer.part,
+                    title: puzzle.title,
+                    answer: Some(value.clone()),
+                    error: None,
+                    status: None,
+                    expected: None,
+                    parse_ms: Some(as_millis(solved.parse_elapsed)),
+                    elapsed_ms: Some(as_millis(answer.elapsed)),
+                },
+                Err(err) => Record::error(puzzle, answer.part, err),
+            })
+            .collect()
+    }
+
+    /// Constructs a new `Record` for a part of the puzzle that could not be solved.
+    #[must_use]
+    pub fn error(puzzle: &Puzzle, part: Part, error: &dyn Display) -> Record {
+        Record {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            title: puzzle.title,
+            answer: None,
+            error: Some(error.to_string()),
+            status: None,
+            expected: None,
+            parse_ms: None,
+            elapsed_ms: None,
+        }
+    }
+
+    /// Compares the answer to the known answer in the provided key.
+    ///
+    /// Records without an answer are not checked.
+    pub fn check(&mut self, key: &AnswerKey) {
+        if let Some(answer) = &self.answer {
+            let status = key.check(self.day, self.part, answer);
+            if status == Status::Fail {
+                self.expected = key.get(self.day, self.part).map(String::from);
+            }
+            self.status = Some(status);
+        }
+    }
+
+    /// Returns `true` if the part could not be solved, or if the answer did not match the known answer; `false` otherwise.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        self.error.is_some() || self.status == Some(Status::Fail)
+    }
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Serializes a part as its number, either `1` or `2`.
+fn serialize_part<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(part.number())
+}
+
+/// Writes the records of a single puzzle in the provided format.
+///
+/// # Errors
+///
+/// Will return an error if writing to the writer fails.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::report::{write_records, Format, Record};
+/// use advent_of_code::solution::Part;
+///
+/// let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+/// let records = vec![Record::error(puzzle, Part::One, &"no answer")];
+///
+/// let mut output = Vec::new();
+/// write_records(&mut output, Format::Json, puzzle, &records).unwrap();
+/// let output = String::from_utf8(output).unwrap();
+/// assert_eq!(
+///     output,
+///     "{\"year\":2015,\"day\":1,\"part\":1,\"title\":\"Not Quite Lisp\",\"error\":\"no answer\"}\n"
+/// );
+/// ```
+pub fn write_records<W: Write>(
+    writer: &mut W,
+    format: Format,
+    puzzle: &Puzzle,
+    records: &[Record],
+) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            writeln!(
+                writer,
+                "Advent of Code - Year {} Day {}: {}",
+                puzzle.year, puzzle.day, puzzle.title
+            )?;
+            for record in records {
+                match (&record.answer, &record.error) {
+                    (Some(answer), _) => match (&record.status, &record.expected) {
+                        (Some(status), Some(expected)) => writeln!(
+                            writer,
+                            "{}: {} [{}, expected {}]",
+                            record.part, answer, status, expected
+                        )?,
+                        (Some(status), None) => {
+                            writeln!(writer, "{}: {} [{}]", record.part, answer, status)?
+                        }
+                        (None, _) => writeln!(writer, "{}: {}", record.part, answer)?,
+                    },
+                    (None, Some(error)) => writeln!(writer, "{}: failed - {}", record.part, error)?,
+                    (None, None) => writeln!(writer, "{}: no answer", record.part)?,
+                }
+            }
+        }
+        Format::Json => {
+            for record in records {
+                serde_json::to_writer(&mut *writer, record)?;
+                writeln!(writer)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Represents the time taken to parse the input and solve each part of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parse: Duration,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+impl Timing {
+    /// Constructs a new `Timing` from the solved puzzle.
+    ///
+    /// Parts with no answer are not timed.
+    #[must_use]
+    pub fn new(puzzle: &Puzzle, solved: &Solved) -> Timing {
+        let elapsed = |part| {
+            solved
+                .answers
+                .iter()
+                .find(|answer| answer.part == part && answer.value.is_ok())
+                .map(|answer| answer.elapsed)
+        };
+
+        Timing {
+            year: puzzle.year,
+            day: puzzle.day,
+            title: puzzle.title,
+            parse: solved.parse_elapsed,
+            part_one: elapsed(Part::One),
+            part_two: elapsed(Part::Two),
+        }
+    }
+
+    /// Returns the time taken to parse the input and solve every part.
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one.unwrap_or_default() + self.part_two.unwrap_or_default()
+    }
+}
+
+/// Sorts the timings from the slowest to the fastest puzzle, and keeps only the provided number of slowest puzzles.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use advent_of_code::report::{slowest, Timing};
+///
+/// let timing = |day, millis| Timing {
+///     year: 2015,
+///     day,
+///     title: "",
+///     parse: Duration::from_millis(millis),
+///     part_one: None,
+///     part_two: None,
+/// };
+///
+/// let mut timings = vec![timing(1, 5), timing(2, 20), timing(3, 10)];
+/// slowest(&mut timings, 2);
+/// let days: Vec<u8> = timings.iter().map(|timing| timing.day).collect();
+/// assert_eq!(days, vec![2, 3]);
+/// ```
+pub fn slowest(timings: &mut Vec<Timing>, count: usize) {
+    timings.sort_by_key(|timing| Reverse(timing.total()));
+    timings.truncate(count);
+}
+
+/// Formats a duration with a unit suited to its size, such as `512 µs`, `12.345 ms` or `2.500 s`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use advent_of_code::report::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_micros(512)), "512 µs");
+/// assert_eq!(format_duration(Duration::from_micros(12345)), "12.345 ms");
+/// assert_eq!(format_duration(Duration::from_millis(2500)), "2.500 s");
+/// ```
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{} µs", duration.as_micros())
+    } else if duration < Duration::from_secs(1) {
+        format!("{:.3} ms", as_millis(duration))
+    } else {
+        format!("{:.3} s", duration.as_secs_f64())
+    }
+}
+
+/// Writes the timings as a table, one row per puzzle.
+///
+/// Parts that were not solved are shown as `-`.
+///
+/// # Errors
+///
+/// Will return an error if writing to the writer fails.
+pub fn write_timings<W: Write>(writer: &mut W, timings: &[Timing]) -> io::Result<()> {
+    let format_part =
+        |part: Option<Duration>| part.map_or_else(|| String::from("-"), format_duration);
+
+    writeln!(
+        writer,
+        "{:<4}  {:>3}  {:<40}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Year", "Day", "Title", "Parse", "Part One", "Part Two", "Total"
+    )?;
+    for timing in timings {
+        writeln!(
+            writer,
+            "{:<4}  {:>3}  {:<40}  {:>12}  {:>12}  {:>12}  {:>12}",
+            timing.year,
+            timing.day,
+            timing.title,
+            format_duration(timing.parse),
+            format_part(timing.part_one),
+            format_part(timing.part_two),
+            format_duration(timing.total())
+        )?;
+    }
+    Ok(())
+}
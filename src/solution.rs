@@ -0,0 +1,301 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::util::ParseError;
+
+/// Result type returned when parsing input or solving a part of a puzzle.
+pub type SolutionResult<T> = Result<T, Box<dyn Error>>;
+
+/// Solution describes types that can solve both parts of an Advent of Code puzzle.
+///
+/// The input is parsed once and shared between both parts.
+pub trait Solution {
+    /// The year of the event the puzzle belongs to.
+    const YEAR: u16;
+
+    /// The day of the event the puzzle was released on.
+    const DAY: u8;
+
+    /// The title of the puzzle.
+    const TITLE: &'static str;
+
+    /// The parsed representation of the puzzle input.
+    type Input;
+
+    /// The answer to the first part of the puzzle.
+    type PartOne: Display;
+
+    /// The answer to the second part of the puzzle.
+    type PartTwo: Display;
+
+    /// Parses the puzzle input.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the input cannot be parsed.
+    fn parse(input: &str) -> SolutionResult<Self::Input>;
+
+    /// Parses the puzzle input, setting aside the lines that cannot be parsed instead of failing.
+    ///
+    /// Inputs that are not parsed one item per line are parsed the same as [`Solution::parse`], and no lines are set aside.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the input cannot be parsed.
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        Ok((Self::parse(input)?, Vec::new()))
+    }
+
+    /// Solves the first part of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the input has no answer.
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne>;
+
+    /// Solves the second part of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the input has no answer.
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo>;
+}
+
+/// Represents one of the two parts of a puzzle.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts of a puzzle, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Returns the part with the provided number.
+    /// If the number is not `1` or `2`, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::solution::Part;
+    ///
+    /// assert_eq!(Part::from_number(2), Some(Part::Two));
+    /// assert_eq!(Part::from_number(3), None);
+    /// ```
+    #[must_use]
+    pub fn from_number(number: u32) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of the part, either `1` or `2`.
+    #[must_use]
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => "Part One".fmt(f),
+            Part::Two => "Part Two".fmt(f),
+        }
+    }
+}
+
+/// Represents the answer to one part of a puzzle, and the time taken to solve it.
+///
+/// Each part is solved separately, so one part failing does not hide the answer to the other.
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub value: SolutionResult<String>,
+    pub elapsed: Duration,
+}
+
+/// Represents the answers to the selected parts of a puzzle, and the time taken to parse the input.
+#[derive(Debug)]
+pub struct Solved {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+    /// The lines of the input that could not be parsed, when parsed leniently.
+    pub rejected: Vec<ParseError>,
+}
+
+/// Represents the answers to both parts of a puzzle.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Answers {
+    pub part_one: String,
+    pub part_two: String,
+}
+
+/// Represents a puzzle in the registry.
+///
+/// The types of a `Solution` are erased so puzzles from every day can be stored in the same table.
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    solve: fn(&str, &[Part], bool) -> SolutionResult<Solved>,
+}
+
+impl Puzzle {
+    /// Constructs a new `Puzzle` from the provided solution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::solution::Puzzle;
+    /// use advent_of_code::year_2015::day_01::Day01;
+    ///
+    /// let puzzle = Puzzle::new::<Day01>();
+    /// assert_eq!(puzzle.day, 1);
+    /// ```
+    #[must_use]
+    pub const fn new<S: Solution>() -> Puzzle {
+        Puzzle {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Returns the path of the input file for the puzzle inside the provided directory, `<dir>/<year>/day-<day>.txt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use advent_of_code::solution::Puzzle;
+    /// use advent_of_code::year_2015::day_01::Day01;
+    ///
+    /// let puzzle = Puzzle::new::<Day01>();
+    /// let path = puzzle.input_path(Path::new("input"));
+    /// assert_eq!(path, PathBuf::from("input/2015/day-01.txt"));
+    /// ```
+    #[must_use]
+    pub fn input_path(&self, dir: &Path) -> PathBuf {
+        dir.join(self.year.to_string())
+            .join(format!("day-{:02}.txt", self.day))
+    }
+
+    /// Parses the provided input and solves both parts of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the input cannot be parsed, or if either part has no answer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::solution::Puzzle;
+    /// use advent_of_code::year_2015::day_01::Day01;
+    ///
+    /// let puzzle = Puzzle::new::<Day01>();
+    /// let answers = puzzle.solve("()())").unwrap();
+    /// assert_eq!(answers.part_one, "-1");
+    /// assert_eq!(answers.part_two, "5");
+    /// ```
+    pub fn solve(&self, input: &str) -> SolutionResult<Answers> {
+        let mut answers = self.solve_parts(input, &Part::ALL)?.answers.into_iter();
+        let mut next = || {
+            answers
+                .next()
+                .map_or_else(|| Ok(String::new()), |answer| answer.value)
+        };
+        let part_one = next()?;
+        let part_two = next()?;
+        Ok(Answers { part_one, part_two })
+    }
+
+    /// Parses the provided input once and solves only the provided parts of the puzzle, in the order provided.
+    ///
+    /// The time taken to parse the input and to solve each part is measured separately.
+    /// Each part has its own result, so a part with no answer does not hide the answers to the other parts.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the input cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::solution::{Part, Puzzle};
+    /// use advent_of_code::year_2015::day_01::Day01;
+    ///
+    /// let puzzle = Puzzle::new::<Day01>();
+    /// let answers = puzzle.solve_parts("()())", &[Part::Two]).unwrap().answers;
+    /// assert_eq!(answers.len(), 1);
+    /// assert_eq!(answers[0].part, Part::Two);
+    /// assert_eq!(answers[0].value.as_deref().unwrap(), "5");
+    /// ```
+    pub fn solve_parts(&self, input: &str, parts: &[Part]) -> SolutionResult<Solved> {
+        (self.solve)(input, parts, false)
+    }
+
+    /// Parses the provided input once, setting aside the lines that cannot be parsed,
+    /// and solves only the provided parts of the puzzle, in the order provided.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the input cannot be parsed, such as an input that is not parsed one item per line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::solution::{Part, Puzzle};
+    /// use advent_of_code::year_2015::day_02::Day02;
+    ///
+    /// let puzzle = Puzzle::new::<Day02>();
+    /// let solved = puzzle.solve_parts_lenient("2x3x4\n2x3\n1x1x10", &[Part::One]).unwrap();
+    /// assert_eq!(solved.answers[0].value.as_deref().unwrap(), "101");
+    /// assert_eq!(solved.rejected.len(), 1);
+    /// assert_eq!(solved.rejected[0].line_number(), Some(2));
+    /// ```
+    pub fn solve_parts_lenient(&self, input: &str, parts: &[Part]) -> SolutionResult<Solved> {
+        (self.solve)(input, parts, true)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part], lenient: bool) -> SolutionResult<Solved> {
+    let start = Instant::now();
+    let (input, rejected) = if lenient {
+        S::parse_lenient(input)?
+    } else {
+        (S::parse(input)?, Vec::new())
+    };
+    let parse_elapsed = start.elapsed();
+
+    let mut answers = Vec::new();
+    for &part in parts {
+        let start = Instant::now();
+        let value = match part {
+            Part::One => S::part_one(&input).map(|answer| answer.to_string()),
+            Part::Two => S::part_two(&input).map(|answer| answer.to_string()),
+        };
+        let elapsed = start.elapsed();
+        answers.push(Answer {
+            part,
+            value,
+            elapsed,
+        });
+    }
+    Ok(Solved {
+        parse_elapsed,
+        answers,
+        rejected,
+    })
+}
@@ -1,36 +1,331 @@
-use std::fmt::Debug;
+use std::any::type_name;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::BufRead;
 use std::str::FromStr;
 
-use regex::{Captures, Match};
+use regex::{Captures, Match, Regex};
+
+/// Error type used when parsing a puzzle input, or a line of a puzzle input, from a `str`.
+///
+/// The error carries the offending line, its line number in the input when known,
+/// the format the line was expected to be in, and the underlying cause when there is one.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use advent_of_code::year_2015::day_02::Present;
+///
+/// let err = Present::from_str("1x1").unwrap_err();
+/// assert_eq!(err.line(), "1x1");
+/// assert_eq!(err.to_string(), "'1x1' was not in format '{length}x{width}x{height}'");
+///
+/// let err = err.at_line(3);
+/// assert_eq!(err.line_number(), Some(3));
+/// assert_eq!(err.to_string(), "line 3: '1x1' was not in format '{length}x{width}x{height}'");
+/// ```
+#[derive(Debug)]
+pub struct ParseError {
+    line: String,
+    line_number: Option<usize>,
+    expected: &'static str,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ParseError {
+    /// Constructs a new `ParseError` for a line that was not in the expected format.
+    pub(crate) fn new(line: &str, expected: &'static str) -> ParseError {
+        ParseError {
+            line: String::from(line),
+            line_number: None,
+            expected,
+            source: None,
+        }
+    }
+
+    /// Sets the underlying cause of the error.
+    pub(crate) fn with_source<E>(mut self, source: E) -> ParseError
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Sets the line number of the offending line, counting from 1.
+    #[must_use]
+    pub fn at_line(mut self, line_number: usize) -> ParseError {
+        self.line_number = Some(line_number);
+        self
+    }
+
+    /// Returns the offending line.
+    #[must_use]
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    /// Returns the line number of the offending line, counting from 1.
+    /// If the line number is not known `None` is returned.
+    #[must_use]
+    pub fn line_number(&self) -> Option<usize> {
+        self.line_number
+    }
+
+    /// Returns a description of the format the line was expected to be in.
+    #[must_use]
+    pub fn expected(&self) -> &str {
+        self.expected
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn Error + 'static))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(line_number) = self.line_number {
+            write!(f, "line {}: ", line_number)?;
+        }
+        write!(f, "'{}' was not in format '{}'", self.line, self.expected)?;
+        if let Some(source) = &self.source {
+            write!(f, ": {}", source)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of parsing an input leniently, with every line that could not be parsed set aside.
+#[derive(Debug)]
+pub struct ParsedLines<T> {
+    /// The items parsed from the lines of the input, in order.
+    pub items: Vec<T>,
+    /// The errors for the lines that could not be parsed, in order.
+    pub rejected: Vec<ParseError>,
+}
+
+/// Parses every non-empty line of the input into `T`.
+///
+/// # Errors
+///
+/// Will return `Err` for the first line that cannot be parsed, with its line number counting from 1.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::util::parse_lines;
+///
+/// let numbers: Vec<u32> = parse_lines("1\n2\n3").unwrap();
+/// assert_eq!(numbers, vec![1, 2, 3]);
+///
+/// let err = parse_lines::<u32>("1\ntwo\n3").unwrap_err();
+/// assert_eq!(err.line(), "two");
+/// assert_eq!(err.line_number(), Some(2));
+/// ```
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    non_empty_lines(input)
+        .map(|(line_number, line)| parse_line(line_number, line))
+        .collect()
+}
+
+/// Parses every non-empty line of the input into `T`, setting aside the lines that cannot be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::util::parse_lines_lenient;
+///
+/// let parsed = parse_lines_lenient::<u32>("1\ntwo\n3");
+/// assert_eq!(parsed.items, vec![1, 3]);
+/// assert_eq!(parsed.rejected.len(), 1);
+/// assert_eq!(parsed.rejected[0].line_number(), Some(2));
+/// ```
+#[must_use]
+pub fn parse_lines_lenient<T>(input: &str) -> ParsedLines<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    let mut parsed = ParsedLines {
+        items: Vec::new(),
+        rejected: Vec::new(),
+    };
+    for (line_number, line) in non_empty_lines(input) {
+        match parse_line(line_number, line) {
+            Ok(item) => parsed.items.push(item),
+            Err(err) => parsed.rejected.push(err),
+        }
+    }
+    parsed
+}
+
+/// Error type used when reading a puzzle input line by line from a `BufRead`.
+#[derive(Debug)]
+pub enum ReadError {
+    /// The reader failed.
+    Io(io::Error),
+    /// A line could not be parsed.
+    Parse(ParseError),
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(err) => Some(err),
+            ReadError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "failed to read input: {}", err),
+            ReadError::Parse(err) => write!(f, "failed to parse input: {}", err),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        ReadError::Parse(err)
+    }
+}
+
+/// Returns an iterator parsing every non-empty line read from the reader into `T`.
+///
+/// Lines are read one at a time, so inputs too large to hold in memory can be processed incrementally.
+/// Unlike [`parse_lines`] the iterator does not stop at the first error,
+/// so it can be collected into a `Result` to fail on the first bad line or filtered to skip bad lines.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::util::{read_lines, ReadError};
+///
+/// let numbers: Result<Vec<u32>, ReadError> = read_lines(&b"1\n2\n3\n"[..]).collect();
+/// assert_eq!(numbers.unwrap(), vec![1, 2, 3]);
+///
+/// let numbers: Result<Vec<u32>, ReadError> = read_lines(&b"1\ntwo\n3\n"[..]).collect();
+/// match numbers.unwrap_err() {
+///     ReadError::Parse(err) => assert_eq!(err.line_number(), Some(2)),
+///     ReadError::Io(_) => unreachable!(),
+/// }
+/// ```
+pub fn read_lines<T, R>(reader: R) -> impl Iterator<Item = Result<T, ReadError>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Box<dyn Error + Send + Sync>>,
+    R: BufRead,
+{
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(parse_line(i + 1, &line).map_err(ReadError::from)),
+            Err(err) => Some(Err(ReadError::from(err))),
+        })
+}
+
+/// Returns the non-empty lines of the input with their line numbers, counting from 1.
+fn non_empty_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| (i + 1, line))
+}
+
+/// Parses a single line into `T`.
+/// Errors which are not already a `ParseError`, such as from parsing a number, expect the name of `T` as the format.
+fn parse_line<T>(line_number: usize, line: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    line.parse().map_err(|err: <T as FromStr>::Err| {
+        let err = match err.into().downcast::<ParseError>() {
+            Ok(err) => *err,
+            Err(source) => ParseError::new(line, type_name::<T>()).with_source(source),
+        };
+        err.at_line(line_number)
+    })
+}
 
 /// A wrapper to hide repetitive method calls parsing capture groups.
-pub struct CapturesWrapper<'t>(Captures<'t>);
+pub(crate) struct CapturesWrapper<'t> {
+    caps: Captures<'t>,
+    line: &'t str,
+    expected: &'static str,
+}
 
 impl<'t> CapturesWrapper<'t> {
-    /// Constructs a new `CapturesWrapper` with the provided caps.
-    pub(crate) fn new(caps: Captures) -> CapturesWrapper {
-        CapturesWrapper(caps)
+    /// Matches the regex against the line.
+    /// If the line does not match, an error expecting the provided format is returned.
+    pub(crate) fn captures(
+        regex: &Regex,
+        line: &'t str,
+        expected: &'static str,
+    ) -> Result<CapturesWrapper<'t>, ParseError> {
+        match regex.captures(line) {
+            Some(caps) => Ok(CapturesWrapper {
+                caps,
+                line,
+                expected,
+            }),
+            None => Err(ParseError::new(line, expected)),
+        }
     }
 
     /// Returns the capture group named key as a `&str`.
     pub(crate) fn as_str(&self, key: &str) -> &str {
-        self.0.name(key).unwrap().as_str()
+        self.caps.name(key).unwrap().as_str()
     }
 
     /// Returns the capture group named key as a `String`.
     pub(crate) fn as_string(&self, key: &str) -> String {
-        self.0.name(key).unwrap().as_str().to_string()
+        self.caps.name(key).unwrap().as_str().to_string()
     }
 
     /// Returns the capture group named key parsed into another type `T`.
-    pub(crate) fn parse<T: FromStr>(&self, key: &str) -> T
+    /// If the capture group cannot be parsed, such as a number too large for `T`, an error is returned.
+    pub(crate) fn parse<T>(&self, key: &str) -> Result<T, ParseError>
     where
-        <T as FromStr>::Err: Debug,
+        T: FromStr,
+        <T as FromStr>::Err: Into<Box<dyn Error + Send + Sync>>,
     {
-        self.0.name(key).unwrap().as_str().parse().unwrap()
+        self.as_str(key).parse().map_err(|err| self.error(err))
     }
 
     pub(crate) fn name(&self, name: &str) -> Option<Match<'t>> {
-        self.0.name(name)
+        self.caps.name(name)
+    }
+
+    /// Returns an error for the matched line with the provided cause.
+    pub(crate) fn error<E>(&self, source: E) -> ParseError
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        ParseError::new(self.line, self.expected).with_source(source)
     }
 }
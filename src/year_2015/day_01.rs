@@ -3,13 +3,18 @@
 Santa is trying to deliver presents in a large apartment building, but he can't find the right floor - the directions he got are a little confusing.
 */
 
+use std::io;
+use std::io::BufRead;
+
+use crate::solution::{Solution, SolutionResult};
+
 /// `InfiniteBuilding` holds state for the current floor, and position.
 ///
 /// The apartment building is very tall, and the basement is very deep; you will never find the top or bottom floors.
 #[derive(Debug)]
 struct InfiniteBuilding {
-    floor: i32,
-    position: i32,
+    floor: i64,
+    position: u64,
 }
 
 impl InfiniteBuilding {
@@ -75,12 +80,48 @@ impl InfiniteBuilding {
 /// assert_eq!(f, -3);
 /// ```
 #[must_use]
-pub fn floor(input: &[u8]) -> i32 {
+pub fn floor(input: &[u8]) -> i64 {
     let mut building = InfiniteBuilding::new();
     input.iter().for_each(|c| building.apply(*c));
     building.floor
 }
 
+/// Returns the floor that the instructions read from the reader take you to.
+///
+/// The instructions are consumed one buffer at a time, so inputs too large to hold in memory can be followed.
+///
+/// # Errors
+///
+/// Will return `Err` if the reader fails.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use std::io::{BufReader, Read};
+///
+/// use advent_of_code::year_2015::day_01::floor_from_reader;
+///
+/// let f = floor_from_reader(&b"))((((("[..]).unwrap();
+/// assert_eq!(f, 3);
+///
+/// let reader = BufReader::new(io::repeat(b'(').take(1_000_000));
+/// assert_eq!(floor_from_reader(reader).unwrap(), 1_000_000);
+/// ```
+pub fn floor_from_reader<R: BufRead>(mut reader: R) -> io::Result<i64> {
+    let mut building = InfiniteBuilding::new();
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return Ok(building.floor);
+        }
+        buffer.iter().for_each(|c| building.apply(*c));
+
+        let length = buffer.len();
+        reader.consume(length);
+    }
+}
+
 /// Returns the position of the first instruction that bring you to the provided floor.
 /// If no instruction takes you to the provided floor `None` is returned.
 ///
@@ -98,7 +139,7 @@ pub fn floor(input: &[u8]) -> i32 {
 /// assert_eq!(p, Some(5));
 /// ```
 #[must_use]
-pub fn position_to_floor(input: &[u8], floor: i32) -> Option<i32> {
+pub fn position_to_floor(input: &[u8], floor: i64) -> Option<u64> {
     if floor == 0 {
         return Some(0);
     }
@@ -112,3 +153,81 @@ pub fn position_to_floor(input: &[u8], floor: i32) -> Option<i32> {
     }
     None
 }
+
+/// Returns the position of the first instruction read from the reader that brings you to the provided floor.
+/// If no instruction takes you to the provided floor `None` is returned.
+///
+/// The instructions are consumed one buffer at a time, and reading stops as soon as the floor is reached.
+///
+/// # Errors
+///
+/// Will return `Err` if the reader fails.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use std::io::{BufReader, Read};
+///
+/// use advent_of_code::year_2015::day_01::position_to_floor_from_reader;
+///
+/// let p = position_to_floor_from_reader(&b"()())"[..], -1).unwrap();
+/// assert_eq!(p, Some(5));
+///
+/// let reader = BufReader::new(io::repeat(b'(').take(1_000_000).chain(&b"))"[..]));
+/// assert_eq!(position_to_floor_from_reader(reader, 999_999).unwrap(), Some(999_999));
+///
+/// let reader = BufReader::new(io::repeat(b'(').take(1_000_000));
+/// assert_eq!(position_to_floor_from_reader(reader, -1).unwrap(), None);
+/// ```
+pub fn position_to_floor_from_reader<R: BufRead>(
+    mut reader: R,
+    floor: i64,
+) -> io::Result<Option<u64>> {
+    if floor == 0 {
+        return Ok(Some(0));
+    }
+
+    let mut building = InfiniteBuilding::new();
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return Ok(None);
+        }
+        for c in buffer.iter() {
+            building.apply(*c);
+            if building.floor == floor {
+                return Ok(Some(building.position));
+            }
+        }
+
+        let length = buffer.len();
+        reader.consume(length);
+    }
+}
+
+/// Solution to Year 2015 Day 1: Not Quite Lisp.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
+
+    type Input = Vec<u8>;
+    type PartOne = i64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(Vec::from(input))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(floor(input))
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        position_to_floor(input, -1)
+            .ok_or_else(|| "the instructions never enter the basement".into())
+    }
+}
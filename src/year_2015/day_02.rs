@@ -4,15 +4,13 @@ The elves are running low on wrapping paper, and so they need to submit an order
 They have a list of the dimensions of each present, and only want to order exactly as much as they need.
 */
 
-use std::error::Error;
-use std::fmt;
-use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use crate::util::CapturesWrapper;
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError};
 
 /// Represents the dimensions of a present in feet.
 #[derive(Debug)]
@@ -85,39 +83,51 @@ impl Present {
 }
 
 impl FromStr for Present {
-    type Err = ParsePresentError;
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Present, ParsePresentError> {
+    fn from_str(s: &str) -> Result<Present, ParseError> {
         static REGEX: Lazy<Regex> =
             Lazy::new(|| Regex::new(r"^(?P<length>\d+)x(?P<width>\d+)x(?P<height>\d+)$").unwrap());
-        match REGEX.captures(s) {
-            Some(caps) => {
-                let caps = CapturesWrapper::new(caps);
-                let length = caps.parse("length");
-                let width = caps.parse("width");
-                let height = caps.parse("height");
-
-                Ok(Present {
-                    length,
-                    width,
-                    height,
-                })
-            }
-            None => Err(ParsePresentError { _priv: () }),
-        }
+
+        let caps = CapturesWrapper::captures(&REGEX, s, "{length}x{width}x{height}")?;
+        let length = caps.parse("length")?;
+        let width = caps.parse("width")?;
+        let height = caps.parse("height")?;
+
+        Ok(Present {
+            length,
+            width,
+            height,
+        })
     }
 }
 
-/// Error type used when parsing a present from a `str`.
-#[derive(Debug)]
-pub struct ParsePresentError {
-    pub(super) _priv: (),
-}
+/// Solution to Year 2015 Day 2: I Was Told There Would Be No Math.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
+
+    type Input = Vec<Present>;
+    type PartOne = u32;
+    type PartTwo = u32;
 
-impl Error for ParsePresentError {}
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((parsed.items, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(input.iter().map(Present::wrapping_paper_needed).sum())
+    }
 
-impl Display for ParsePresentError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        "provided string was not in format '{length}x{width}x{height}'".fmt(f)
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        Ok(input.iter().map(Present::ribbon_needed).sum())
     }
 }
@@ -7,6 +7,8 @@ However, the elf back at the north pole has had a little too much eggnog, and so
 
 use hashbrown::HashSet;
 
+use crate::solution::{Solution, SolutionResult};
+
 /// Represents a position in the grid.
 #[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
 struct Position {
@@ -141,6 +143,35 @@ impl InfiniteGrid {
     }
 }
 
+/// Solution to Year 2015 Day 3: Perfectly Spherical Houses in a Vacuum.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Perfectly Spherical Houses in a Vacuum";
+
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(String::from(input))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        let mut grid = InfiniteGrid::new(1);
+        input.chars().for_each(|c| grid.move_position(c));
+        Ok(grid.visited())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let mut grid = InfiniteGrid::new(2);
+        input.chars().for_each(|c| grid.move_position(c));
+        Ok(grid.visited())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::year_2015::day_03::{InfiniteGrid, Position};
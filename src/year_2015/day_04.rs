@@ -10,6 +10,8 @@ To mine Advent Coins, you must find Santa the lowest positive number that produc
 use crypto::digest::Digest;
 use crypto::md5::Md5;
 
+use crate::solution::{Solution, SolutionResult};
+
 /// Finds the lowest number that when added to the provided key has an MD5 hash with the provided number of leading zeroes.
 ///
 /// # Examples
@@ -32,7 +34,7 @@ pub fn find_number(key: &[u8], leading_zeroes: u8) -> Option<u64> {
     let mut hasher = Md5::new();
 
     // Find the number of leading zeroes to check for taking into account an odd number of bytes
-    let (is_even, leading_bytes) = if leading_zeroes % 2 == 0 {
+    let (is_even, leading_bytes) = if leading_zeroes.is_multiple_of(2) {
         (true, leading_zeroes / 2)
     } else {
         (false, leading_zeroes / 2 + 1)
@@ -63,3 +65,30 @@ pub fn find_number(key: &[u8], leading_zeroes: u8) -> Option<u64> {
     }
     None
 }
+
+/// Solution to Year 2015 Day 4: The Ideal Stocking Stuffer.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
+
+    type Input = String;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(String::from(input.trim()))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        find_number(input.as_bytes(), 5)
+            .ok_or_else(|| "no number produces five leading zeroes".into())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        find_number(input.as_bytes(), 6)
+            .ok_or_else(|| "no number produces six leading zeroes".into())
+    }
+}
@@ -6,6 +6,8 @@ Santa needs help figuring out which strings in his text file are naughty or nice
 
 use once_cell::sync::Lazy;
 
+use crate::solution::{Solution, SolutionResult};
+
 /// Counts the vowels in the provided `str`.
 fn count_vowels(s: &[u8]) -> usize {
     static VOWELS: Lazy<&'static [u8]> = Lazy::new(|| b"aeiou");
@@ -146,3 +148,28 @@ pub fn is_nice_word2(s: &str) -> bool {
     contains_pair_of_letters_twice(s.as_ref())
         && contains_letter_that_repeats_with_letter_between(s.as_ref())
 }
+
+/// Solution to Year 2015 Day 5: Doesn't He Have Intern-Elves For This?
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Doesn't He Have Intern-Elves For This?";
+
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(String::from(input))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(input.lines().filter(|s| is_nice_word(s)).count())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        Ok(input.lines().filter(|s| is_nice_word2(s)).count())
+    }
+}
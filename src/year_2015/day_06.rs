@@ -6,29 +6,81 @@ Because your neighbors keep defeating you in the holiday house decorating contes
 Furthermore, because you've been especially nice this year, Santa has mailed you instructions on how to display the ideal lighting configuration.
 */
 
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::{BufRead, Write};
+use std::ops::{Add, Sub};
 use std::str::FromStr;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use crate::util::CapturesWrapper;
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError, ReadError};
 
 /// Represents a point or bulb in the grid.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Point {
     pub x: usize,
     pub y: usize,
 }
 
 /// Represents the operations of a light bulb.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Operation {
     Toggle,
     TurnOn,
     TurnOff,
+    /// Sets the brightness to the provided level.
+    Set(i32),
+    /// Turns the brightness down by the provided amount.
+    Dim(i32),
+}
+
+impl Operation {
+    /// Applies the operation to the whole bulb.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{AdjustableBulb, Bulb, Operation};
+    ///
+    /// let mut bulb = AdjustableBulb::default();
+    /// Operation::Set(5).apply(&mut bulb);
+    /// Operation::Dim(2).apply(&mut bulb);
+    /// assert_eq!(bulb.brightness(), 3);
+    /// ```
+    pub fn apply<T: Bulb>(self, bulb: &mut T) {
+        match self {
+            Operation::Toggle => bulb.toggle(),
+            Operation::TurnOn => bulb.turn_on(),
+            Operation::TurnOff => bulb.turn_off(),
+            Operation::Set(level) => bulb.set(level),
+            Operation::Dim(amount) => bulb.dim(amount),
+        }
+    }
+}
+
+/// Represents a color channel of a light bulb.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Channel {
+    /// Returns the index of the channel in a color.
+    fn index(self) -> usize {
+        match self {
+            Channel::Red => 0,
+            Channel::Green => 1,
+            Channel::Blue => 2,
+        }
+    }
 }
 
 /// Bulb describes types that can be used as a light bulb.
@@ -40,6 +92,46 @@ pub trait Bulb: Clone + Default {
     fn turn_off(&mut self);
 
     fn brightness(&self) -> i32;
+
+    /// Sets the brightness of the light as near to the provided level as it can go.
+    ///
+    /// By default the light is turned off until its brightness is zero, then turned on until it reaches the level,
+    /// at most once per level.
+    fn set(&mut self, level: i32) {
+        for _ in 0..self.brightness() {
+            self.turn_off();
+        }
+        for _ in 0..level {
+            if self.brightness() >= level {
+                break;
+            }
+            self.turn_on();
+        }
+    }
+
+    /// Turns the brightness of the light down by the provided amount.
+    ///
+    /// By default the light is turned off once per amount.
+    fn dim(&mut self, amount: i32) {
+        for _ in 0..amount {
+            self.turn_off();
+        }
+    }
+
+    /// Returns the red, green, and blue brightness of the light.
+    ///
+    /// By default every channel has the light's brightness, so the light is a shade of grey.
+    fn color(&self) -> [i32; 3] {
+        let brightness = self.brightness();
+        [brightness; 3]
+    }
+
+    /// Applies the operation to a single color channel of the light.
+    ///
+    /// By default the light has no separate channels, so the operation applies to the whole light.
+    fn apply_to_channel(&mut self, _channel: Channel, operation: Operation) {
+        operation.apply(self);
+    }
 }
 
 /// Represents a bulb that is either on or off.
@@ -72,23 +164,35 @@ impl Bulb for SimpleBulb {
             0
         }
     }
+
+    /// Set the light as on if the level is above zero, and off otherwise.
+    fn set(&mut self, level: i32) {
+        self.state = level > 0;
+    }
+
+    /// Set the light as off if the amount is above zero.
+    fn dim(&mut self, amount: i32) {
+        if amount > 0 {
+            self.state = false;
+        }
+    }
 }
 
-/// Represents a bulb with brightness of zero or more.
+/// Represents a bulb with brightness of zero or more, up to `i32::MAX`.
 #[derive(Clone, Debug, Default)]
 pub struct AdjustableBulb {
     state: i32,
 }
 
 impl Bulb for AdjustableBulb {
-    /// Turns up the brightness by two.
+    /// Turns up the brightness by two, to a maximum of `i32::MAX`.
     fn toggle(&mut self) {
-        self.state += 2;
+        self.state = self.state.saturating_add(2);
     }
 
-    /// turns up brightness by one.
+    /// turns up brightness by one, to a maximum of `i32::MAX`.
     fn turn_on(&mut self) {
-        self.state += 1;
+        self.state = self.state.saturating_add(1);
     }
 
     /// Turns brightness down by one to a minimum of zero.
@@ -102,15 +206,218 @@ impl Bulb for AdjustableBulb {
     fn brightness(&self) -> i32 {
         self.state
     }
+
+    /// Sets the brightness to the level, to a minimum of zero.
+    fn set(&mut self, level: i32) {
+        self.state = level.max(0);
+    }
+
+    /// Turns brightness down by the amount to a minimum of zero.
+    fn dim(&mut self, amount: i32) {
+        self.state = (self.state - amount.max(0)).max(0);
+    }
+}
+
+/// Represents a bulb with brightness from zero up to a maximum, ten unless provided.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_06::{Bulb, DimmerBulb};
+///
+/// let mut bulb: DimmerBulb = DimmerBulb::default();
+/// for _ in 0..6 {
+///     bulb.toggle();
+/// }
+/// assert_eq!(bulb.brightness(), 10);
+///
+/// let mut bulb = DimmerBulb::<3>::default();
+/// bulb.set(5);
+/// assert_eq!(bulb.brightness(), 3);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DimmerBulb<const MAX: i32 = 10> {
+    state: i32,
+}
+
+impl<const MAX: i32> Bulb for DimmerBulb<MAX> {
+    /// Turns up the brightness by two to a maximum of `MAX`.
+    fn toggle(&mut self) {
+        self.set(self.state.saturating_add(2));
+    }
+
+    /// Turns up the brightness by one to a maximum of `MAX`.
+    fn turn_on(&mut self) {
+        self.set(self.state.saturating_add(1));
+    }
+
+    /// Turns brightness down by one to a minimum of zero.
+    fn turn_off(&mut self) {
+        self.dim(1);
+    }
+
+    /// Returns the light's brightness.
+    fn brightness(&self) -> i32 {
+        self.state
+    }
+
+    /// Sets the brightness to the level, between zero and `MAX`.
+    fn set(&mut self, level: i32) {
+        self.state = level.clamp(0, MAX.max(0));
+    }
+
+    /// Turns brightness down by the amount to a minimum of zero.
+    fn dim(&mut self, amount: i32) {
+        self.set(self.state - amount.max(0));
+    }
+}
+
+/// Represents a bulb with separate red, green, and blue channels, each with brightness of zero or more.
+///
+/// Operations on the whole bulb apply to every channel, and its brightness is the total of the channels,
+/// up to `i32::MAX` like any other bulb.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_06::{Bulb, Channel, ColorBulb, Operation};
+///
+/// let mut bulb = ColorBulb::default();
+/// bulb.turn_on();
+/// bulb.apply_to_channel(Channel::Red, Operation::Set(5));
+/// bulb.apply_to_channel(Channel::Blue, Operation::TurnOff);
+/// assert_eq!(bulb.color(), [5, 1, 0]);
+/// assert_eq!(bulb.brightness(), 6);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ColorBulb {
+    channels: [AdjustableBulb; 3],
+}
+
+impl Bulb for ColorBulb {
+    /// Turns up the brightness of every channel by two.
+    fn toggle(&mut self) {
+        self.channels.iter_mut().for_each(Bulb::toggle);
+    }
+
+    /// Turns up the brightness of every channel by one.
+    fn turn_on(&mut self) {
+        self.channels.iter_mut().for_each(Bulb::turn_on);
+    }
+
+    /// Turns the brightness of every channel down by one to a minimum of zero.
+    fn turn_off(&mut self) {
+        self.channels.iter_mut().for_each(Bulb::turn_off);
+    }
+
+    /// Returns the total brightness of the channels, to a maximum of `i32::MAX`.
+    fn brightness(&self) -> i32 {
+        let total: i64 = self
+            .channels
+            .iter()
+            .map(|channel| i64::from(channel.brightness()))
+            .sum();
+        i32::try_from(total).unwrap_or(i32::MAX)
+    }
+
+    /// Sets the brightness of every channel to the level, to a minimum of zero.
+    fn set(&mut self, level: i32) {
+        self.channels
+            .iter_mut()
+            .for_each(|channel| channel.set(level));
+    }
+
+    /// Turns the brightness of every channel down by the amount to a minimum of zero.
+    fn dim(&mut self, amount: i32) {
+        self.channels
+            .iter_mut()
+            .for_each(|channel| channel.dim(amount));
+    }
+
+    /// Returns the brightness of the red, green, and blue channels.
+    fn color(&self) -> [i32; 3] {
+        let [red, green, blue] = &self.channels;
+        [red.brightness(), green.brightness(), blue.brightness()]
+    }
+
+    /// Applies the operation to the channel only.
+    fn apply_to_channel(&mut self, channel: Channel, operation: Operation) {
+        operation.apply(&mut self.channels[channel.index()]);
+    }
+}
+
+/// Represents how a [`LightGrid`] stores its lights.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Storage {
+    /// Every bulb is stored separately, and an instruction changes every bulb it covers one at a time.
+    Dense,
+    /// Rectangles of bulbs which have always had the same instructions applied are stored as one bulb,
+    /// and an instruction changes every rectangle it covers one at a time.
+    Compressed,
+}
+
+/// Error type used when an instruction cannot be applied to a [`LightGrid`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InstructionError {
+    /// A corner of the instruction's rectangle is outside the grid.
+    OutsideGrid {
+        point: Point,
+        width: usize,
+        height: usize,
+    },
+    /// The start point of the instruction's rectangle is after its end point.
+    Inverted {
+        start_point: Point,
+        end_point: Point,
+    },
+}
+
+impl Error for InstructionError {}
+
+impl Display for InstructionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InstructionError::OutsideGrid {
+                point,
+                width,
+                height,
+            } => write!(
+                f,
+                "the point {},{} is outside the {}x{} grid",
+                point.x, point.y, width, height
+            ),
+            InstructionError::Inverted {
+                start_point,
+                end_point,
+            } => write!(
+                f,
+                "the start point {},{} is after the end point {},{}",
+                start_point.x, start_point.y, end_point.x, end_point.y
+            ),
+        }
+    }
 }
 
 /// Represents a finite grid of lights.
+#[derive(Clone)]
 pub struct LightGrid<T: Bulb> {
-    lights: Vec<Vec<T>>,
+    width: usize,
+    height: usize,
+    lights: Lights<T>,
 }
 
 const GRID_CAPACITY: usize = 1000;
 
+/// The most lights a grid can have, so its total brightness fits in an `i64` even with every bulb as bright as it can be,
+/// as 2<sup>32</sup> bulbs at `i32::MAX` are just under `i64::MAX`.
+const MAX_LIGHTS: u64 = 1 << 32;
+
+#[derive(Clone)]
+enum Lights<T> {
+    Dense(Vec<Vec<T>>),
+    Compressed(CompressedLights<T>),
+}
+
 impl<T: Bulb> LightGrid<T> {
     /// Constructs a new full `LightGrid<T>`.
     ///
@@ -125,8 +432,123 @@ impl<T: Bulb> LightGrid<T> {
     /// ```
     #[must_use]
     pub fn new() -> LightGrid<T> {
+        LightGrid::with_storage(Storage::Dense)
+    }
+
+    /// Constructs a new `LightGrid<T>` storing its lights as provided.
+    ///
+    /// A compressed grid starts with a single bulb and splits it up as instructions are applied,
+    /// so it is faster than a dense grid when the instructions cover large rectangles with few distinct corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{LightGrid, LightInstruction, SimpleBulb, Storage};
+    /// use std::str::FromStr;
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::with_storage(Storage::Compressed);
+    /// grid.apply_operation(&LightInstruction::from_str("turn on 0,0 through 999,999").unwrap()).unwrap();
+    /// grid.apply_operation(&LightInstruction::from_str("turn off 499,499 through 500,500").unwrap()).unwrap();
+    /// assert_eq!(grid.total_brightness(), 999996);
+    /// assert_eq!(grid.storage(), Storage::Compressed);
+    /// ```
+    #[must_use]
+    pub fn with_storage(storage: Storage) -> LightGrid<T> {
+        LightGrid::with_size_and_storage(GRID_CAPACITY, GRID_CAPACITY, storage)
+    }
+
+    /// Constructs a new full `LightGrid<T>` with the provided number of columns and rows of lights.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the width or height is zero, or if the grid has more than 2<sup>32</sup> lights,
+    /// as the total brightness of a larger grid might not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{LightGrid, SimpleBulb};
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::with_size(20, 10);
+    /// let instruction = grid.parse_instruction("turn on 0,0 through 19,9").unwrap();
+    /// grid.apply_operation(&instruction).unwrap();
+    /// assert_eq!(grid.total_brightness(), 200);
+    /// ```
+    #[must_use]
+    pub fn with_size(width: usize, height: usize) -> LightGrid<T> {
+        LightGrid::with_size_and_storage(width, height, Storage::Dense)
+    }
+
+    /// Constructs a new `LightGrid<T>` with the provided number of columns and rows of lights, storing its lights as provided.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the width or height is zero, or if the grid has more than 2<sup>32</sup> lights,
+    /// as the total brightness of a larger grid might not fit in an `i64`.
+    #[must_use]
+    pub fn with_size_and_storage(width: usize, height: usize, storage: Storage) -> LightGrid<T> {
+        assert!(width > 0 && height > 0, "the grid must have lights");
+        assert!(
+            width
+                .checked_mul(height)
+                .is_some_and(|lights| lights as u64 <= MAX_LIGHTS),
+            "the grid must have at most {} lights",
+            MAX_LIGHTS
+        );
+        let lights = match storage {
+            Storage::Dense => Lights::Dense(vec![vec![T::default(); height]; width]),
+            Storage::Compressed => Lights::Compressed(CompressedLights::new(width, height)),
+        };
         LightGrid {
-            lights: vec![vec![T::default(); GRID_CAPACITY]; GRID_CAPACITY],
+            width,
+            height,
+            lights,
+        }
+    }
+
+    /// Returns the number of columns of lights in the grid.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows of lights in the grid.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns how the grid stores its lights.
+    #[must_use]
+    pub fn storage(&self) -> Storage {
+        match self.lights {
+            Lights::Dense(_) => Storage::Dense,
+            Lights::Compressed(_) => Storage::Compressed,
+        }
+    }
+
+    /// Returns the bulb at the provided point.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the point is outside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{Bulb, LightGrid, LightInstruction, Point, SimpleBulb};
+    /// use std::str::FromStr;
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::new();
+    /// grid.apply_operation(&LightInstruction::from_str("turn on 0,0 through 1,1").unwrap()).unwrap();
+    /// assert_eq!(grid.bulb(&Point { x: 1, y: 1 }).brightness(), 1);
+    /// assert_eq!(grid.bulb(&Point { x: 2, y: 1 }).brightness(), 0);
+    /// ```
+    #[must_use]
+    pub fn bulb(&self, point: &Point) -> &T {
+        match &self.lights {
+            Lights::Dense(lights) => &lights[point.x][point.y],
+            Lights::Compressed(lights) => lights.bulb(point),
         }
     }
 
@@ -140,37 +562,353 @@ impl<T: Bulb> LightGrid<T> {
     /// let mut grid: LightGrid<SimpleBulb> = LightGrid::new();
     /// let instruction = LightInstruction {
     ///     operation: Operation::Toggle,
+    ///     channel: None,
     ///     start_point: Point { x: 0, y: 0 },
     ///     end_point: Point { x: 0, y: 999 }
     /// };
-    /// grid.apply_operation(&instruction);
+    /// grid.apply_operation(&instruction).unwrap();
     /// let count = grid.total_brightness();
     /// assert_eq!(count, 1000);
     /// ```
     #[must_use]
-    pub fn total_brightness(&self) -> i32 {
-        let mut count = 0;
-        for x in 0..GRID_CAPACITY {
-            for y in 0..GRID_CAPACITY {
-                count += self.lights[x][y].brightness();
-            }
+    pub fn total_brightness(&self) -> i64 {
+        match &self.lights {
+            Lights::Dense(lights) => lights
+                .iter()
+                .flatten()
+                .map(|bulb| i64::from(bulb.brightness()))
+                .sum(),
+            Lights::Compressed(lights) => lights.total_brightness(),
         }
-        count
     }
 
-    // TODO: Update with happy path doc tests
+    /// Returns the tables to query the brightness of rectangles of lights in the grid as it is now.
+    ///
+    /// Building the tables visits every light once, after which every query but [`LightRegions::max_brightness`]
+    /// takes the same time however large the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{LightGrid, Point, SimpleBulb};
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::new();
+    /// grid.apply_operation(&grid.parse_instruction("turn on 0,0 through 999,999").unwrap()).unwrap();
+    /// grid.apply_operation(&grid.parse_instruction("turn off 499,499 through 500,500").unwrap()).unwrap();
+    ///
+    /// let regions = grid.regions();
+    /// assert_eq!(regions.lit_count(&Point { x: 0, y: 0 }, &Point { x: 499, y: 499 }).unwrap(), 249999);
+    /// ```
+    #[must_use]
+    pub fn regions(&self) -> LightRegions {
+        LightRegions::new(self)
+    }
+
+    /// Checks the provided instruction covers a rectangle of lights in the grid.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a corner of the instruction's rectangle is outside the grid,
+    /// or if its start point is after its end point in either direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_06::{InstructionError, LightGrid, LightInstruction, Point, SimpleBulb};
+    ///
+    /// let grid: LightGrid<SimpleBulb> = LightGrid::with_size(10, 10);
+    /// assert!(grid.validate(&LightInstruction::from_str("toggle 0,0 through 9,9").unwrap()).is_ok());
+    ///
+    /// let instruction = LightInstruction::from_str("toggle 0,0 through 9,10").unwrap();
+    /// assert_eq!(
+    ///     grid.validate(&instruction),
+    ///     Err(InstructionError::OutsideGrid { point: Point { x: 9, y: 10 }, width: 10, height: 10 })
+    /// );
+    ///
+    /// let instruction = LightInstruction::from_str("toggle 5,0 through 4,9").unwrap();
+    /// assert!(matches!(grid.validate(&instruction), Err(InstructionError::Inverted { .. })));
+    /// ```
+    pub fn validate(&self, instruction: &LightInstruction) -> Result<(), InstructionError> {
+        validate_rectangle(
+            self.width,
+            self.height,
+            &instruction.start_point,
+            &instruction.end_point,
+        )
+    }
+
+    /// Parses an instruction in the format `{toggle|turn on|turn off|set {level}|dim {amount}} [{red|green|blue}] {x1},{y1} through {x2},{y2}`,
+    /// accepting any coordinates within the grid.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the line is not in the format, or if the instruction is not valid for the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{LightGrid, SimpleBulb};
+    ///
+    /// let grid: LightGrid<SimpleBulb> = LightGrid::with_size(5000, 10);
+    /// assert!(grid.parse_instruction("turn on 1000,0 through 4999,9").is_ok());
+    /// assert!(grid.parse_instruction("turn on 1000,0 through 4999,10").is_err());
+    /// assert!(grid.parse_instruction("turn on 1000,0 through 999,9").is_err());
+    /// ```
+    pub fn parse_instruction(&self, s: &str) -> Result<LightInstruction, ParseError> {
+        let instruction = parse_instruction(s)?;
+        self.validate(&instruction)
+            .map_err(|err| ParseError::new(s, INSTRUCTION_FORMAT).with_source(err))?;
+        Ok(instruction)
+    }
+
     /// Applies the provided instruction to the lights in the grid.
-    pub fn apply_operation(&mut self, instruction: &LightInstruction) {
-        for x in instruction.start_point.x..=instruction.end_point.x {
-            for y in instruction.start_point.y..=instruction.end_point.y {
-                match instruction.operation {
-                    Operation::Toggle => self.lights[x][y].toggle(),
-                    Operation::TurnOn => self.lights[x][y].turn_on(),
-                    Operation::TurnOff => self.lights[x][y].turn_off(),
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the instruction is not valid for the grid, as described in [`LightGrid::validate`].
+    /// The grid is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_06::{LightGrid, LightInstruction, SimpleBulb};
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::new();
+    /// grid.apply_operation(&LightInstruction::from_str("turn on 0,0 through 9,9").unwrap()).unwrap();
+    /// assert_eq!(grid.total_brightness(), 100);
+    ///
+    /// assert!(grid.apply_operation(&LightInstruction::from_str("turn on 9,9 through 0,0").unwrap()).is_err());
+    /// assert_eq!(grid.total_brightness(), 100);
+    /// ```
+    pub fn apply_operation(
+        &mut self,
+        instruction: &LightInstruction,
+    ) -> Result<(), InstructionError> {
+        self.validate(instruction)?;
+        self.apply(instruction);
+        Ok(())
+    }
+
+    /// Applies the provided instruction, which must already be valid for the grid.
+    /// Returns the change in the total brightness of the grid.
+    fn apply(&mut self, instruction: &LightInstruction) -> i64 {
+        match &mut self.lights {
+            Lights::Dense(lights) => {
+                let (start, end) = (&instruction.start_point, &instruction.end_point);
+                let mut change = 0;
+                for column in &mut lights[start.x..=end.x] {
+                    for bulb in &mut column[start.y..=end.y] {
+                        change += i64::from(instruction.apply_to(bulb));
+                    }
                 }
+                change
             }
+            Lights::Compressed(lights) => lights.apply_operation(instruction),
         }
     }
+
+    /// Applies every instruction read from the reader to the lights in the grid, one line at a time,
+    /// and returns the number of instructions applied.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the reader fails or a line is not an instruction for the grid, as described in [`LightGrid::parse_instruction`].
+    /// Instructions before the failing line have already been applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{LightGrid, SimpleBulb};
+    ///
+    /// let instructions = "turn on 0,0 through 999,999\nturn off 499,499 through 500,500\n";
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::new();
+    /// let applied = grid.apply_operations_from_reader(instructions.as_bytes()).unwrap();
+    /// assert_eq!(applied, 2);
+    /// assert_eq!(grid.total_brightness(), 999996);
+    /// ```
+    pub fn apply_operations_from_reader<R: BufRead>(
+        &mut self,
+        reader: R,
+    ) -> Result<usize, ReadError> {
+        let mut applied = 0;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let instruction = self
+                .parse_instruction(&line)
+                .map_err(|err| err.at_line(i + 1))?;
+            self.apply(&instruction);
+            applied += 1;
+        }
+        Ok(applied)
+    }
+
+    /// Writes the grid as a binary PGM image, with a pixel for every light in grayscale by its brightness.
+    ///
+    /// The image's maximum gray value is the brightest light, so the brightest lights are white.
+    /// A grid of [`SimpleBulb`] is black and white.
+    /// Brightness above 65535 is written as 65535.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{AdjustableBulb, LightGrid};
+    ///
+    /// let mut grid: LightGrid<AdjustableBulb> = LightGrid::with_size(3, 2);
+    /// grid.apply_operation(&grid.parse_instruction("toggle 0,0 through 1,0").unwrap()).unwrap();
+    /// grid.apply_operation(&grid.parse_instruction("turn on 1,0 through 2,1").unwrap()).unwrap();
+    ///
+    /// let mut pgm = Vec::new();
+    /// grid.write_pgm(&mut pgm).unwrap();
+    /// assert_eq!(pgm, b"P5\n3 2\n3\n\x02\x03\x01\x00\x01\x01");
+    /// ```
+    pub fn write_pgm<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_netpbm(writer, "P5", |bulb| [bulb.brightness()])
+    }
+
+    /// Writes the grid as a binary PPM image, with a pixel for every light in the color given by [`Bulb::color`].
+    ///
+    /// The image's maximum color value is the brightest channel of any light.
+    /// Brightness above 65535 is written as 65535.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{LightGrid, SimpleBulb};
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::with_size(2, 1);
+    /// grid.apply_operation(&grid.parse_instruction("turn on 1,0 through 1,0").unwrap()).unwrap();
+    ///
+    /// let mut ppm = Vec::new();
+    /// grid.write_ppm(&mut ppm).unwrap();
+    /// assert_eq!(ppm, b"P6\n2 1\n1\n\x00\x00\x00\x01\x01\x01");
+    /// ```
+    pub fn write_ppm<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_netpbm(writer, "P6", Bulb::color)
+    }
+
+    /// Writes a binary Netpbm image with the samples of every light, one row of lights at a time.
+    fn write_netpbm<W, F, const N: usize>(
+        &self,
+        writer: &mut W,
+        magic: &str,
+        samples: F,
+    ) -> io::Result<()>
+    where
+        W: Write,
+        F: Fn(&T) -> [i32; N],
+    {
+        let mut max_value = 1;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let brightest = samples(self.bulb(&Point { x, y })).iter().copied().max();
+                max_value = max_value.max(brightest.unwrap_or(0));
+            }
+        }
+        let max_value = max_value.min(i32::from(u16::MAX));
+
+        writeln!(
+            writer,
+            "{}\n{} {}\n{}",
+            magic, self.width, self.height, max_value
+        )?;
+        let mut row = Vec::new();
+        for y in 0..self.height {
+            row.clear();
+            for x in 0..self.width {
+                for &sample in &samples(self.bulb(&Point { x, y })) {
+                    let sample = sample.clamp(0, max_value);
+                    if max_value > i32::from(u8::MAX) {
+                        row.extend_from_slice(&(sample as u16).to_be_bytes());
+                    } else {
+                        row.push(sample as u8);
+                    }
+                }
+            }
+            writer.write_all(&row)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the grid as text with at most the provided number of columns and rows of characters,
+    /// each shading the average brightness of the block of lights it covers.
+    ///
+    /// Characters run from `' '` for unlit, through `.:-=+*#%`, to `'@'` for as bright as the brightest light.
+    /// Every row of characters ends with a newline.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the number of columns or rows is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{LightGrid, SimpleBulb};
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::new();
+    /// grid.apply_operation(&grid.parse_instruction("turn on 0,0 through 499,999").unwrap()).unwrap();
+    /// grid.apply_operation(&grid.parse_instruction("turn on 500,0 through 749,499").unwrap()).unwrap();
+    /// assert_eq!(grid.render_ascii(4, 2), "@@@ \n@@  \n");
+    /// ```
+    #[must_use]
+    pub fn render_ascii(&self, columns: usize, rows: usize) -> String {
+        const SHADES: &[u8] = b" .:-=+*#%@";
+
+        assert!(
+            columns > 0 && rows > 0,
+            "the rendering must have characters"
+        );
+        let columns = columns.min(self.width);
+        let rows = rows.min(self.height);
+
+        let mut blocks = vec![vec![0_i64; columns]; rows];
+        let mut max_brightness = 0;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let brightness = self.bulb(&Point { x, y }).brightness().max(0);
+                max_brightness = max_brightness.max(brightness);
+                blocks[y * rows / self.height][x * columns / self.width] += i64::from(brightness);
+            }
+        }
+
+        // Light i of length falls in block i * blocks / length.
+        let block_length = |block: usize, blocks: usize, length: usize| {
+            ((block + 1) * length).div_ceil(blocks) - (block * length).div_ceil(blocks)
+        };
+
+        let mut rendering = String::with_capacity((columns + 1) * rows);
+        for (row, block_row) in blocks.iter().enumerate() {
+            let lights_y = block_length(row, rows, self.height);
+            for (column, total) in block_row.iter().enumerate() {
+                let lights_x = block_length(column, columns, self.width);
+                let lights = (lights_x * lights_y) as i64;
+                let shade = if *total == 0 {
+                    0
+                } else {
+                    let brightest = lights * i64::from(max_brightness);
+                    1 + (*total * (SHADES.len() as i64 - 2) / brightest) as usize
+                };
+                rendering.push(char::from(SHADES[shade]));
+            }
+            rendering.push('\n');
+        }
+        rendering
+    }
 }
 
 impl<T: Bulb> Default for LightGrid<T> {
@@ -179,62 +917,674 @@ impl<T: Bulb> Default for LightGrid<T> {
     }
 }
 
+/// Checks the rectangle between the start and end points is inside a grid of the provided size.
+/// Returns the number of levels of a sparse table over `n` cells, one for each power of two up to `n`.
+fn levels(n: usize) -> usize {
+    (usize::BITS - n.leading_zeros()) as usize
+}
+
+/// Returns the number of cells a run of `2^level` cells can start at, out of `n` cells.
+fn starts(n: usize, level: usize) -> usize {
+    n + 1 - (1 << level)
+}
+
+fn validate_rectangle(
+    width: usize,
+    height: usize,
+    start: &Point,
+    end: &Point,
+) -> Result<(), InstructionError> {
+    for &point in &[*start, *end] {
+        if point.x >= width || point.y >= height {
+            return Err(InstructionError::OutsideGrid {
+                point,
+                width,
+                height,
+            });
+        }
+    }
+    if start.x > end.x || start.y > end.y {
+        return Err(InstructionError::Inverted {
+            start_point: *start,
+            end_point: *end,
+        });
+    }
+    Ok(())
+}
+
+/// Answers queries on the brightness of rectangles of lights in a [`LightGrid`], built by [`LightGrid::regions`].
+///
+/// Sums and lit counts come from summed-area tables, where each entry holds the total of every light above and to the left of it,
+/// so any rectangle's total is found from the entries at its four corners in constant time.
+///
+/// The maximum brightness comes from a sparse table over both rows and columns,
+/// holding the maximum of every rectangle of lights with power of two sides,
+/// so any rectangle is covered by four overlapping entries and found in constant time.
+/// Adjacent rows and columns of identical lights are merged into cells first, as they never change a maximum,
+/// so the table holds `c * r * (log2(c) + 1) * (log2(r) + 1)` brightness values for `c` columns and `r` rows of cells.
+/// That is about 45 MB for the puzzle input, which has around 430 distinct columns and rows,
+/// but up to 400 MB for a 1000x1000 grid with no identical adjacent rows or columns.
+pub struct LightRegions {
+    width: usize,
+    height: usize,
+    brightness: Vec<i64>,
+    lit: Vec<u64>,
+    /// The cell of each column of lights, and the number of cells across.
+    column_cells: Vec<usize>,
+    columns: usize,
+    /// The cell of each row of lights.
+    row_cells: Vec<usize>,
+    /// The maxima of every rectangle of `2^kx` by `2^ky` cells, indexed by `ky` then `kx`,
+    /// each stored row by row with one entry for every cell a rectangle can start at.
+    maxima: Vec<Vec<Vec<i32>>>,
+}
+
+impl LightRegions {
+    fn new<T: Bulb>(grid: &LightGrid<T>) -> LightRegions {
+        let (width, height) = (grid.width, grid.height);
+        let stride = width + 1;
+        let mut brightness = vec![0; stride * (height + 1)];
+        let mut lit = vec![0; stride * (height + 1)];
+        let mut bulbs = vec![0; width * height];
+
+        for y in 0..height {
+            let (mut row_brightness, mut row_lit) = (0, 0);
+            for x in 0..width {
+                let bulb = grid.bulb(&Point { x, y }).brightness();
+                row_brightness += i64::from(bulb);
+                row_lit += u64::from(bulb > 0);
+
+                let i = (y + 1) * stride + x + 1;
+                brightness[i] = brightness[i - stride] + row_brightness;
+                lit[i] = lit[i - stride] + row_lit;
+                bulbs[y * width + x] = bulb;
+            }
+        }
+
+        // Each cell is represented by its first column and row of lights.
+        // Columns are compared across every row, then rows only across the columns that start a cell.
+        let bulb = |x: usize, y: usize| bulbs[y * width + x];
+        let mut columns = Vec::new();
+        let column_cells: Vec<usize> = (0..width)
+            .map(|x| {
+                if x == 0 || (0..height).any(|y| bulb(x, y) != bulb(x - 1, y)) {
+                    columns.push(x);
+                }
+                columns.len() - 1
+            })
+            .collect();
+        let mut rows = Vec::new();
+        let row_cells: Vec<usize> = (0..height)
+            .map(|y| {
+                if y == 0 || columns.iter().any(|&x| bulb(x, y) != bulb(x, y - 1)) {
+                    rows.push(y);
+                }
+                rows.len() - 1
+            })
+            .collect();
+
+        let cells: Vec<i32> = rows
+            .iter()
+            .flat_map(|&y| columns.iter().map(move |&x| bulb(x, y)))
+            .collect();
+        let mut maxima = vec![vec![cells]];
+        for kx in 1..levels(columns.len()) {
+            let (half, across) = (1 << (kx - 1), starts(columns.len(), kx));
+            let previous = &maxima[0][kx - 1];
+            let previous_across = starts(columns.len(), kx - 1);
+            let level = (0..rows.len())
+                .flat_map(|y| {
+                    let row = &previous[y * previous_across..];
+                    (0..across).map(move |x| row[x].max(row[x + half]))
+                })
+                .collect();
+            maxima[0].push(level);
+        }
+        for ky in 1..levels(rows.len()) {
+            let (half, down) = (1 << (ky - 1), starts(rows.len(), ky));
+            let level = maxima[ky - 1]
+                .iter()
+                .enumerate()
+                .map(|(kx, previous)| {
+                    let across = starts(columns.len(), kx);
+                    (0..down * across)
+                        .map(|i| previous[i].max(previous[i + half * across]))
+                        .collect()
+                })
+                .collect();
+            maxima.push(level);
+        }
+
+        LightRegions {
+            width,
+            height,
+            brightness,
+            lit,
+            column_cells,
+            columns: columns.len(),
+            row_cells,
+            maxima,
+        }
+    }
+
+    /// Returns the total brightness of the lights in the rectangle between the start and end points, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the rectangle is not in the grid, as described in [`LightGrid::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{AdjustableBulb, LightGrid, Point};
+    ///
+    /// let mut grid: LightGrid<AdjustableBulb> = LightGrid::with_size(10, 10);
+    /// grid.apply_operation(&grid.parse_instruction("toggle 0,0 through 4,4").unwrap()).unwrap();
+    ///
+    /// let regions = grid.regions();
+    /// assert_eq!(regions.brightness(&Point { x: 3, y: 3 }, &Point { x: 9, y: 9 }).unwrap(), 8);
+    /// assert!(regions.brightness(&Point { x: 3, y: 3 }, &Point { x: 2, y: 9 }).is_err());
+    /// ```
+    pub fn brightness(&self, start: &Point, end: &Point) -> Result<i64, InstructionError> {
+        validate_rectangle(self.width, self.height, start, end)?;
+        Ok(self.rectangle_sum(&self.brightness, start, end))
+    }
+
+    /// Returns the number of lights with a brightness above zero in the rectangle between the start and end points, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the rectangle is not in the grid, as described in [`LightGrid::validate`].
+    pub fn lit_count(&self, start: &Point, end: &Point) -> Result<u64, InstructionError> {
+        validate_rectangle(self.width, self.height, start, end)?;
+        Ok(self.rectangle_sum(&self.lit, start, end))
+    }
+
+    /// Returns the brightness of the brightest light in the rectangle between the start and end points, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the rectangle is not in the grid, as described in [`LightGrid::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{AdjustableBulb, LightGrid, Point};
+    ///
+    /// let mut grid: LightGrid<AdjustableBulb> = LightGrid::with_size(10, 10);
+    /// grid.apply_operation(&grid.parse_instruction("toggle 0,0 through 4,4").unwrap()).unwrap();
+    /// grid.apply_operation(&grid.parse_instruction("turn on 4,4 through 9,9").unwrap()).unwrap();
+    ///
+    /// let regions = grid.regions();
+    /// assert_eq!(regions.max_brightness(&Point { x: 0, y: 0 }, &Point { x: 9, y: 9 }).unwrap(), 3);
+    /// assert_eq!(regions.max_brightness(&Point { x: 5, y: 0 }, &Point { x: 9, y: 9 }).unwrap(), 1);
+    /// ```
+    pub fn max_brightness(&self, start: &Point, end: &Point) -> Result<i32, InstructionError> {
+        validate_rectangle(self.width, self.height, start, end)?;
+        let (x1, x2) = (self.column_cells[start.x], self.column_cells[end.x]);
+        let (y1, y2) = (self.row_cells[start.y], self.row_cells[end.y]);
+        let kx = levels(x2 - x1 + 1) - 1;
+        let ky = levels(y2 - y1 + 1) - 1;
+
+        let level = &self.maxima[ky][kx];
+        let across = starts(self.columns, kx);
+        let at = |x: usize, y: usize| level[y * across + x];
+        let (x3, y3) = (x2 + 1 - (1 << kx), y2 + 1 - (1 << ky));
+        Ok(at(x1, y1).max(at(x3, y1)).max(at(x1, y3)).max(at(x3, y3)))
+    }
+
+    /// Returns the total brightness of each row of lights, from the top row down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_06::{LightGrid, SimpleBulb};
+    ///
+    /// let mut grid: LightGrid<SimpleBulb> = LightGrid::with_size(4, 3);
+    /// grid.apply_operation(&grid.parse_instruction("turn on 1,1 through 3,2").unwrap()).unwrap();
+    ///
+    /// let regions = grid.regions();
+    /// assert_eq!(regions.row_histogram(), vec![0, 3, 3]);
+    /// assert_eq!(regions.column_histogram(), vec![0, 2, 2, 2]);
+    /// ```
+    #[must_use]
+    pub fn row_histogram(&self) -> Vec<i64> {
+        (0..self.height)
+            .map(|y| {
+                let start = Point { x: 0, y };
+                let end = Point {
+                    x: self.width - 1,
+                    y,
+                };
+                self.rectangle_sum(&self.brightness, &start, &end)
+            })
+            .collect()
+    }
+
+    /// Returns the total brightness of each column of lights, from the left column across.
+    #[must_use]
+    pub fn column_histogram(&self) -> Vec<i64> {
+        (0..self.width)
+            .map(|x| {
+                let start = Point { x, y: 0 };
+                let end = Point {
+                    x,
+                    y: self.height - 1,
+                };
+                self.rectangle_sum(&self.brightness, &start, &end)
+            })
+            .collect()
+    }
+
+    /// Returns the total of the summed-area table over a rectangle already known to be in the grid.
+    fn rectangle_sum<N>(&self, table: &[N], start: &Point, end: &Point) -> N
+    where
+        N: Copy + Add<Output = N> + Sub<Output = N>,
+    {
+        let stride = self.width + 1;
+        let at = |x: usize, y: usize| table[y * stride + x];
+        at(end.x + 1, end.y + 1) + at(start.x, start.y)
+            - at(start.x, end.y + 1)
+            - at(end.x + 1, start.y)
+    }
+}
+
+/// Records the instructions applied to a [`LightGrid`], to step back through them and find where a bulb was changed.
+pub struct LightRecorder<T: Bulb> {
+    initial: LightGrid<T>,
+    initial_brightness: i64,
+    grid: LightGrid<T>,
+    instructions: Vec<LightInstruction>,
+    brightness: Vec<i64>,
+}
+
+impl<T: Bulb> LightRecorder<T> {
+    /// Constructs a new `LightRecorder<T>` applying instructions to the provided grid.
+    ///
+    /// A copy of the grid is kept so instructions can be undone.
+    #[must_use]
+    pub fn new(grid: LightGrid<T>) -> LightRecorder<T> {
+        LightRecorder {
+            initial: grid.clone(),
+            initial_brightness: grid.total_brightness(),
+            grid,
+            instructions: Vec::new(),
+            brightness: Vec::new(),
+        }
+    }
+
+    /// Returns the grid with every recorded instruction applied.
+    #[must_use]
+    pub fn grid(&self) -> &LightGrid<T> {
+        &self.grid
+    }
+
+    /// Returns the recorded instructions, in the order they were applied.
+    #[must_use]
+    pub fn instructions(&self) -> &[LightInstruction] {
+        &self.instructions
+    }
+
+    /// Returns the total brightness of the grid after each recorded instruction.
+    #[must_use]
+    pub fn brightness_history(&self) -> &[i64] {
+        &self.brightness
+    }
+
+    /// Applies the provided instruction to the grid and records it,
+    /// returning the total brightness of the grid afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the instruction is not valid for the grid, as described in [`LightGrid::validate`].
+    /// The instruction is not recorded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_06::{LightGrid, LightInstruction, LightRecorder, SimpleBulb};
+    ///
+    /// let mut recorder = LightRecorder::new(LightGrid::<SimpleBulb>::new());
+    /// for instruction in &["turn on 0,0 through 9,9", "toggle 0,0 through 19,0", "turn off 0,0 through 0,9"] {
+    ///     recorder.record(LightInstruction::from_str(instruction).unwrap()).unwrap();
+    /// }
+    /// assert_eq!(recorder.brightness_history(), &[100, 100, 91]);
+    /// ```
+    pub fn record(&mut self, instruction: LightInstruction) -> Result<i64, InstructionError> {
+        self.grid.validate(&instruction)?;
+        let brightness = self
+            .brightness
+            .last()
+            .copied()
+            .unwrap_or(self.initial_brightness)
+            + self.grid.apply(&instruction);
+        self.instructions.push(instruction);
+        self.brightness.push(brightness);
+        Ok(brightness)
+    }
+
+    /// Undoes the last provided number of recorded instructions, or every instruction if fewer were recorded,
+    /// and returns the number of instructions undone.
+    ///
+    /// The grid is rebuilt by applying the remaining instructions to the original grid again,
+    /// as turning a bulb on or off cannot be reversed without knowing its state before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_06::{LightGrid, LightInstruction, LightRecorder, SimpleBulb};
+    ///
+    /// let mut recorder = LightRecorder::new(LightGrid::<SimpleBulb>::new());
+    /// for instruction in &["turn on 0,0 through 9,9", "turn on 0,0 through 19,0", "turn off 0,0 through 0,9"] {
+    ///     recorder.record(LightInstruction::from_str(instruction).unwrap()).unwrap();
+    /// }
+    /// assert_eq!(recorder.undo(2), 2);
+    /// assert_eq!(recorder.grid().total_brightness(), 100);
+    /// assert_eq!(recorder.undo(5), 1);
+    /// assert_eq!(recorder.grid().total_brightness(), 0);
+    /// ```
+    pub fn undo(&mut self, count: usize) -> usize {
+        let count = count.min(self.instructions.len());
+        if count == 0 {
+            return 0;
+        }
+        let remaining = self.instructions.len() - count;
+        self.instructions.truncate(remaining);
+        self.brightness.truncate(remaining);
+
+        self.grid = self.initial.clone();
+        for instruction in &self.instructions {
+            self.grid.apply(instruction);
+        }
+        count
+    }
+
+    /// Returns the index of the last recorded instruction which changed the bulb at the provided point.
+    /// If no recorded instruction changed the bulb `None` is returned.
+    ///
+    /// An instruction covering the bulb without changing it, such as turning on a bulb which is already on, is skipped.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the point is outside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_06::{LightGrid, LightInstruction, LightRecorder, Point, SimpleBulb};
+    ///
+    /// let mut recorder = LightRecorder::new(LightGrid::<SimpleBulb>::new());
+    /// for instruction in &["turn on 0,0 through 9,9", "turn on 0,0 through 19,0", "turn off 10,0 through 10,0"] {
+    ///     recorder.record(LightInstruction::from_str(instruction).unwrap()).unwrap();
+    /// }
+    /// assert_eq!(recorder.last_change(&Point { x: 0, y: 0 }), Some(0));
+    /// assert_eq!(recorder.last_change(&Point { x: 11, y: 0 }), Some(1));
+    /// assert_eq!(recorder.last_change(&Point { x: 10, y: 0 }), Some(2));
+    /// assert_eq!(recorder.last_change(&Point { x: 10, y: 1 }), None);
+    /// ```
+    #[must_use]
+    pub fn last_change(&self, point: &Point) -> Option<usize> {
+        let mut bulb = self.initial.bulb(point).clone();
+        let mut last_change = None;
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            let (start, end) = (&instruction.start_point, &instruction.end_point);
+            if !(start.x..=end.x).contains(&point.x) || !(start.y..=end.y).contains(&point.y) {
+                continue;
+            }
+            let before = bulb.color();
+            instruction.apply_to(&mut bulb);
+            if bulb.color() != before {
+                last_change = Some(i);
+            }
+        }
+        last_change
+    }
+}
+
+/// Stores the lights of a grid as columns and rows of bulbs,
+/// where every bulb stands for the rectangle of lights between the start of its column and row and the start of the next.
+#[derive(Clone)]
+struct CompressedLights<T> {
+    width: usize,
+    height: usize,
+    columns: Vec<usize>,
+    rows: Vec<usize>,
+    bulbs: Vec<Vec<T>>,
+}
+
+impl<T: Bulb> CompressedLights<T> {
+    fn new(width: usize, height: usize) -> CompressedLights<T> {
+        CompressedLights {
+            width,
+            height,
+            columns: vec![0],
+            rows: vec![0],
+            bulbs: vec![vec![T::default()]],
+        }
+    }
+
+    fn bulb(&self, point: &Point) -> &T {
+        assert!(
+            point.x < self.width && point.y < self.height,
+            "the point {},{} is outside the grid",
+            point.x,
+            point.y
+        );
+        let column = self.columns.partition_point(|&start| start <= point.x) - 1;
+        let row = self.rows.partition_point(|&start| start <= point.y) - 1;
+        &self.bulbs[column][row]
+    }
+
+    /// Returns the number of lights in each column or row starting at the provided starts.
+    fn lengths(starts: &[usize], end: usize) -> Vec<i64> {
+        starts
+            .iter()
+            .zip(starts.iter().skip(1).chain(&[end]))
+            .map(|(start, end)| (end - start) as i64)
+            .collect()
+    }
+
+    fn total_brightness(&self) -> i64 {
+        let widths = Self::lengths(&self.columns, self.width);
+        let heights = Self::lengths(&self.rows, self.height);
+
+        self.bulbs
+            .iter()
+            .zip(&widths)
+            .map(|(column, width)| {
+                column
+                    .iter()
+                    .zip(&heights)
+                    .map(|(bulb, height)| i64::from(bulb.brightness()) * width * height)
+                    .sum::<i64>()
+            })
+            .sum()
+    }
+
+    fn apply_operation(&mut self, instruction: &LightInstruction) -> i64 {
+        let (start, end) = (&instruction.start_point, &instruction.end_point);
+        let first_column = self.split_column(start.x);
+        let last_column = self.split_column(end.x + 1);
+        let first_row = self.split_row(start.y);
+        let last_row = self.split_row(end.y + 1);
+
+        let widths = Self::lengths(&self.columns, self.width);
+        let heights = Self::lengths(&self.rows, self.height);
+        let mut change = 0;
+        for (column, width) in self.bulbs[first_column..last_column]
+            .iter_mut()
+            .zip(&widths[first_column..last_column])
+        {
+            for (bulb, height) in column[first_row..last_row]
+                .iter_mut()
+                .zip(&heights[first_row..last_row])
+            {
+                change += i64::from(instruction.apply_to(bulb)) * width * height;
+            }
+        }
+        change
+    }
+
+    /// Makes a column start at x, copying the column it splits, and returns its index.
+    fn split_column(&mut self, x: usize) -> usize {
+        if x >= self.width {
+            return self.columns.len();
+        }
+        match self.columns.binary_search(&x) {
+            Ok(index) => index,
+            Err(index) => {
+                self.columns.insert(index, x);
+                let column = self.bulbs[index - 1].clone();
+                self.bulbs.insert(index, column);
+                index
+            }
+        }
+    }
+
+    /// Makes a row start at y, copying the row it splits in every column, and returns its index.
+    fn split_row(&mut self, y: usize) -> usize {
+        if y >= self.height {
+            return self.rows.len();
+        }
+        match self.rows.binary_search(&y) {
+            Ok(index) => index,
+            Err(index) => {
+                self.rows.insert(index, y);
+                for column in &mut self.bulbs {
+                    let bulb = column[index - 1].clone();
+                    column.insert(index, bulb);
+                }
+                index
+            }
+        }
+    }
+}
+
 /// Represents an instruction for all lights between the provided start and end points.
-#[derive(Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LightInstruction {
     pub operation: Operation,
+    /// The color channel the operation applies to, or `None` for the whole light.
+    pub channel: Option<Channel>,
     pub start_point: Point,
     pub end_point: Point,
 }
 
-// TODO: Add comments on proper string format
-impl FromStr for LightInstruction {
-    type Err = ParseInstructionError;
+impl LightInstruction {
+    /// Applies the instruction's operation to the bulb, and returns the change in its brightness.
+    fn apply_to<T: Bulb>(&self, bulb: &mut T) -> i32 {
+        let before = bulb.brightness();
+        match self.channel {
+            Some(channel) => bulb.apply_to_channel(channel, self.operation),
+            None => self.operation.apply(bulb),
+        }
+        bulb.brightness() - before
+    }
+}
 
-    fn from_str(s: &str) -> Result<LightInstruction, ParseInstructionError> {
-        static REGEX: Lazy<Regex> = Lazy::new(|| {
-            Regex::new(r"^(?P<operation>toggle|turn on|turn off) (?P<x1>\d{1,3}),(?P<y1>\d{1,3}) through (?P<x2>\d{1,3}),(?P<y2>\d{1,3})$").unwrap()
-        });
+impl FromStr for LightInstruction {
+    type Err = ParseError;
 
-        match REGEX.captures(s) {
-            Some(caps) => {
-                let caps = CapturesWrapper::new(caps);
-                let operation = match caps.as_str("operation") {
-                    "toggle" => Operation::Toggle,
-                    "turn on" => Operation::TurnOn,
-                    "turn off" => Operation::TurnOff,
-                    _ => unimplemented!(),
+    /// Parses an instruction in the format `{toggle|turn on|turn off|set {level}|dim {amount}} [{red|green|blue}] {x1},{y1} through {x2},{y2}`,
+    /// accepting coordinates within the default 1000x1000 grid.
+    fn from_str(s: &str) -> Result<LightInstruction, ParseError> {
+        let instruction = parse_instruction(s)?;
+        for &point in &[instruction.start_point, instruction.end_point] {
+            if point.x >= GRID_CAPACITY || point.y >= GRID_CAPACITY {
+                let err = InstructionError::OutsideGrid {
+                    point,
+                    width: GRID_CAPACITY,
+                    height: GRID_CAPACITY,
                 };
-
-                let x1 = caps.parse("x1");
-                let y1 = caps.parse("y1");
-                let x2 = caps.parse("x2");
-                let y2 = caps.parse("y2");
-
-                let start_point = Point { x: x1, y: y1 };
-                let end_point = Point { x: x2, y: y2 };
-
-                Ok(LightInstruction {
-                    operation,
-                    start_point,
-                    end_point,
-                })
+                return Err(ParseError::new(s, INSTRUCTION_FORMAT).with_source(err));
             }
-            None => Err(ParseInstructionError { _priv: () }),
         }
+        Ok(instruction)
     }
 }
 
-/// Error type used when parsing a light instruction from a `str`.
-#[derive(Debug)]
-pub struct ParseInstructionError {
-    pub(super) _priv: (),
+const INSTRUCTION_FORMAT: &str =
+    "{toggle|turn on|turn off|set {level}|dim {amount}} [{red|green|blue}] {x1},{y1} through {x2},{y2}";
+
+/// Parses an instruction with any coordinates, leaving them to be checked against the grid it is applied to.
+fn parse_instruction(s: &str) -> Result<LightInstruction, ParseError> {
+    static REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^(?P<operation>toggle|turn on|turn off|set (?P<level>\d+)|dim (?P<amount>\d+))(?: (?P<channel>red|green|blue))? (?P<x1>\d+),(?P<y1>\d+) through (?P<x2>\d+),(?P<y2>\d+)$").unwrap()
+    });
+
+    let caps = CapturesWrapper::captures(&REGEX, s, INSTRUCTION_FORMAT)?;
+    let operation = match caps.as_str("operation") {
+        "toggle" => Operation::Toggle,
+        "turn on" => Operation::TurnOn,
+        "turn off" => Operation::TurnOff,
+        _ if caps.name("level").is_some() => Operation::Set(caps.parse("level")?),
+        _ if caps.name("amount").is_some() => Operation::Dim(caps.parse("amount")?),
+        // The operation group only matches the alternatives above, and `set` and `dim` always capture their number.
+        _ => unreachable!("regex only matches known operations"),
+    };
+    let channel = match caps.name("channel").map(|channel| channel.as_str()) {
+        Some("red") => Some(Channel::Red),
+        Some("green") => Some(Channel::Green),
+        Some("blue") => Some(Channel::Blue),
+        _ => None,
+    };
+
+    let x1 = caps.parse("x1")?;
+    let y1 = caps.parse("y1")?;
+    let x2 = caps.parse("x2")?;
+    let y2 = caps.parse("y2")?;
+
+    let start_point = Point { x: x1, y: y1 };
+    let end_point = Point { x: x2, y: y2 };
+
+    Ok(LightInstruction {
+        operation,
+        channel,
+        start_point,
+        end_point,
+    })
 }
 
-impl Error for ParseInstructionError {}
+/// Solution to Year 2015 Day 6: Probably a Fire Hazard.
+pub struct Day06;
 
-impl Display for ParseInstructionError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        "provided string was not in format '(toggle|turn on|turn off) (0-999),(0-999) through (0-999),(0-999)'".fmt(f)
+impl Solution for Day06 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Probably a Fire Hazard";
+
+    type Input = Vec<LightInstruction>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((parsed.items, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        let mut grid = LightGrid::<SimpleBulb>::new();
+        for instruction in input {
+            grid.apply_operation(instruction)?;
+        }
+        Ok(grid.total_brightness())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let mut grid = LightGrid::<AdjustableBulb>::new();
+        for instruction in input {
+            grid.apply_operation(instruction)?;
+        }
+        Ok(grid.total_brightness())
     }
 }
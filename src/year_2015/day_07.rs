@@ -1,41 +1,204 @@
-use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::Write;
+use std::iter::FromIterator;
 use std::str::FromStr;
 
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use crate::util::CapturesWrapper;
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError};
 
-#[derive(Clone)]
-enum Signal {
+/// Represents an input of a gate.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Signal {
+    /// A specific value.
     Source(u16),
+    /// The signal of another wire.
     Wire(String),
 }
 
-impl From<&str> for Signal {
-    fn from(s: &str) -> Signal {
-        let test = s.parse::<u16>();
-        match test {
-            Ok(value) => Signal::Source(value),
-            Err(_) => Signal::Wire(String::from(s)),
+impl Display for Signal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Signal::Source(value) => write!(f, "{}", value),
+            Signal::Wire(wire) => write!(f, "{}", wire),
         }
     }
 }
 
-#[derive(Clone)]
-enum Gate {
+impl Signal {
+    /// Parses the capture group named key as a wire, or as a value if it is a number.
+    fn parse(caps: &CapturesWrapper, key: &str) -> Result<Signal, ParseError> {
+        let s = caps.as_str(key);
+        if s.bytes().all(|b| b.is_ascii_digit()) {
+            caps.parse(key).map(Signal::Source)
+        } else {
+            Ok(Signal::Wire(String::from(s)))
+        }
+    }
+}
+
+/// Represents the gate providing the signal of a wire.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Gate {
+    /// Provides the input signal unchanged.
     NoOp(Signal),
+    /// Provides the bitwise complement of the input signal.
     Not(Signal),
+    /// Provides the bitwise AND of the input signals.
     And(Signal, Signal),
+    /// Provides the bitwise OR of the input signals.
     Or(Signal, Signal),
+    /// Provides the first input signal left-shifted by the second.
     LeftShift(Signal, Signal),
+    /// Provides the first input signal right-shifted by the second.
     RightShift(Signal, Signal),
 }
 
+impl Display for Gate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Gate::NoOp(signal) => write!(f, "{}", signal),
+            Gate::Not(signal) => write!(f, "NOT {}", signal),
+            Gate::And(lhs, rhs) => write!(f, "{} AND {}", lhs, rhs),
+            Gate::Or(lhs, rhs) => write!(f, "{} OR {}", lhs, rhs),
+            Gate::LeftShift(lhs, rhs) => write!(f, "{} LSHIFT {}", lhs, rhs),
+            Gate::RightShift(lhs, rhs) => write!(f, "{} RSHIFT {}", lhs, rhs),
+        }
+    }
+}
+
+impl Gate {
+    /// Returns the signals the gate takes as inputs, in order.
+    #[must_use]
+    pub fn signals(&self) -> Vec<&Signal> {
+        match self {
+            Gate::NoOp(signal) | Gate::Not(signal) => vec![signal],
+            Gate::And(lhs, rhs)
+            | Gate::Or(lhs, rhs)
+            | Gate::LeftShift(lhs, rhs)
+            | Gate::RightShift(lhs, rhs) => vec![lhs, rhs],
+        }
+    }
+
+    /// Returns the signals the gate takes as inputs, in order, to be rewired.
+    fn signals_mut(&mut self) -> Vec<&mut Signal> {
+        match self {
+            Gate::NoOp(signal) | Gate::Not(signal) => vec![signal],
+            Gate::And(lhs, rhs)
+            | Gate::Or(lhs, rhs)
+            | Gate::LeftShift(lhs, rhs)
+            | Gate::RightShift(lhs, rhs) => vec![lhs, rhs],
+        }
+    }
+
+    /// Returns the wires the gate takes its inputs from, in order.
+    pub fn inputs(&self) -> impl Iterator<Item = &str> {
+        self.signals()
+            .into_iter()
+            .filter_map(|signal| match signal {
+                Signal::Source(_) => None,
+                Signal::Wire(wire) => Some(wire.as_str()),
+            })
+    }
+
+    /// Returns the Graphviz node shape drawn for the type of the gate.
+    fn shape(&self) -> &'static str {
+        match self {
+            Gate::NoOp(_) => "ellipse",
+            Gate::Not(_) => "invtriangle",
+            Gate::And(_, _) => "box",
+            Gate::Or(_, _) => "diamond",
+            Gate::LeftShift(_, _) => "larrow",
+            Gate::RightShift(_, _) => "rarrow",
+        }
+    }
+
+    /// Returns the output of the gate, looking up the signal of each input wire with the provided function.
+    ///
+    /// Shifting by 16 or more bits shifts every bit out.
+    fn evaluate<F>(&self, signal_of: F) -> u16
+    where
+        F: Fn(&str) -> u16,
+    {
+        let value = |signal: &Signal| match signal {
+            Signal::Source(value) => *value,
+            Signal::Wire(wire) => signal_of(wire),
+        };
+        match self {
+            Gate::NoOp(v1) => value(v1),
+            Gate::Not(v1) => !value(v1),
+            Gate::And(v1, v2) => value(v1) & value(v2),
+            Gate::Or(v1, v2) => value(v1) | value(v2),
+            Gate::LeftShift(v1, v2) => value(v1).checked_shl(u32::from(value(v2))).unwrap_or(0),
+            Gate::RightShift(v1, v2) => value(v1).checked_shr(u32::from(value(v2))).unwrap_or(0),
+        }
+    }
+}
+
+/// Represents the gates eliminated by [`Circuit::optimize`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Optimization {
+    /// The number of gates replaced by the constant signal they always provide.
+    pub folded: usize,
+    /// The number of inputs rewired past a chain of direct connections.
+    pub collapsed: usize,
+    /// The number of wires removed because they do not feed any of the outputs.
+    pub removed: usize,
+}
+
+/// Error type used when the signals of a circuit cannot be resolved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CircuitError {
+    /// A gate takes its input from a wire that no instruction provides a signal to.
+    UndefinedWire { wire: String, input_of: String },
+    /// The wires form a feedback loop, listed in order from and back to the same wire.
+    Cycle(Vec<String>),
+}
+
+impl Error for CircuitError {}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::UndefinedWire { wire, input_of } => write!(
+                f,
+                "wire '{}' is an input of wire '{}' but no signal is provided to it",
+                wire, input_of
+            ),
+            CircuitError::Cycle(path) => {
+                write!(f, "the wires form a loop: {}", path.join(" -> "))
+            }
+        }
+    }
+}
+
+/// Error type used when an instruction is constructed with a wire name that cannot be parsed back.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidWireError(pub String);
+
+impl Error for InvalidWireError {}
+
+impl Display for InvalidWireError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' is not a wire, wires are named with one or more lowercase letters",
+            self.0
+        )
+    }
+}
+
 pub struct Circuit {
     wires: HashMap<String, Gate>,
-    signals: RefCell<HashMap<String, u16>>,
+    signals: HashMap<String, u16>,
+    /// The wires taking an input from each wire.
+    dependents: HashMap<String, HashSet<String>>,
 }
 
 impl Circuit {
@@ -43,79 +206,804 @@ impl Circuit {
     pub fn new() -> Circuit {
         Circuit {
             wires: HashMap::new(),
-            signals: RefCell::new(HashMap::new()),
+            signals: HashMap::new(),
+            dependents: HashMap::new(),
         }
     }
 
     pub fn signal(&self, wire: &str) -> Option<u16> {
-        self.signals.borrow().get(wire).copied()
+        self.signals.get(wire).copied()
+    }
+
+    /// Returns the gate connected to the wire.
+    /// If no gate is connected to the wire `None` is returned.
+    #[must_use]
+    pub fn gate(&self, wire: &str) -> Option<&Gate> {
+        self.wires.get(wire)
+    }
+
+    /// Returns the instruction connecting every wire of the circuit to its gate, ordered by wire.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_07::{Circuit, CircuitInstruction, Gate, Signal};
+    ///
+    /// let mut circuit = Circuit::new();
+    /// circuit.add_instruction(CircuitInstruction::new("y", Gate::Not(Signal::Wire(String::from("x")))).unwrap());
+    /// circuit.add_instruction(CircuitInstruction::new("x", Gate::NoOp(Signal::Source(123))).unwrap());
+    ///
+    /// let instructions: Vec<String> = circuit.instructions().iter().map(ToString::to_string).collect();
+    /// assert_eq!(instructions, vec!["123 -> x", "NOT x -> y"]);
+    /// ```
+    #[must_use]
+    pub fn instructions(&self) -> Vec<CircuitInstruction> {
+        let mut instructions: Vec<CircuitInstruction> = self
+            .wires
+            .iter()
+            .map(|(wire, gate)| CircuitInstruction {
+                wire: wire.clone(),
+                output: gate.clone(),
+            })
+            .collect();
+        instructions.sort_by(|a, b| a.wire.cmp(&b.wire));
+        instructions
     }
 
+    /// Connects the gate of the instruction to its wire, replacing any gate already connected to it.
+    ///
+    /// The signals of the wire and every wire downstream of it are cleared, to be resolved again.
     pub fn add_instruction(&mut self, instruction: CircuitInstruction) {
-        self.wires.insert(instruction.wire, instruction.output);
+        let CircuitInstruction { wire, output } = instruction;
+        if let Some(previous) = self.wires.get(&wire) {
+            for input in previous.inputs() {
+                if let Some(dependents) = self.dependents.get_mut(input) {
+                    dependents.remove(&wire);
+                }
+            }
+        }
+        for input in output.inputs() {
+            self.dependents
+                .entry(String::from(input))
+                .or_default()
+                .insert(wire.clone());
+        }
+
+        self.invalidate(&wire);
+        self.wires.insert(wire, output);
     }
 
-    pub fn resolve(&mut self) {
-        self.wires.keys().for_each(|key| {
-            self.resolve_wire(key);
+    /// Provides the signal directly to the wire, replacing any gate already connected to it,
+    /// and resolves again only the wires downstream of it.
+    /// Returns the number of wires whose signals were resolved again, including the overridden wire.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a wire downstream of the overridden wire cannot be resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_07::{Circuit, CircuitInstruction};
+    ///
+    /// let mut circuit = Circuit::new();
+    /// for instruction in &["123 -> x", "456 -> y", "x AND y -> d", "NOT y -> i"] {
+    ///     circuit.add_instruction(CircuitInstruction::from_str(instruction).unwrap());
+    /// }
+    /// circuit.resolve().unwrap();
+    /// assert_eq!(circuit.signal("d"), Some(72));
+    ///
+    /// assert_eq!(circuit.override_wire("x", 456).unwrap(), 2);
+    /// assert_eq!(circuit.signal("d"), Some(456));
+    /// assert_eq!(circuit.signal("i"), Some(65079));
+    /// ```
+    pub fn override_wire(&mut self, wire: &str, signal: u16) -> Result<usize, CircuitError> {
+        self.add_instruction(CircuitInstruction {
+            wire: String::from(wire),
+            output: Gate::NoOp(Signal::Source(signal)),
         });
+
+        let mut resolved = 0;
+        let mut stack = vec![wire];
+        let mut visited = HashSet::new();
+        while let Some(wire) = stack.pop() {
+            if !visited.insert(wire) || !self.wires.contains_key(wire) {
+                continue;
+            }
+            if !self.signals.contains_key(wire) {
+                Circuit::resolve_wire(&self.wires, &mut self.signals, wire)?;
+            }
+            resolved += 1;
+            if let Some(dependents) = self.dependents.get(wire) {
+                stack.extend(dependents.iter().map(String::as_str));
+            }
+        }
+        Ok(resolved)
     }
 
-    pub fn reset(&mut self) {
-        self.signals.borrow_mut().clear();
+    /// Writes the circuit as a Graphviz DOT graph, with a node for every wire and constant,
+    /// and an edge from every input to the wire it feeds.
+    ///
+    /// The shape of each wire is the type of its gate: an ellipse for a direct connection, an inverted triangle for `NOT`,
+    /// a box for `AND`, a diamond for `OR`, and arrows for `LSHIFT` and `RSHIFT`.
+    /// Wires are labelled with their signal once resolved.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_07::{Circuit, CircuitInstruction};
+    ///
+    /// let mut circuit = Circuit::new();
+    /// circuit.add_instruction(CircuitInstruction::from_str("123 -> x").unwrap());
+    /// circuit.add_instruction(CircuitInstruction::from_str("x LSHIFT 2 -> f").unwrap());
+    /// circuit.resolve().unwrap();
+    ///
+    /// let mut dot = Vec::new();
+    /// circuit.write_dot(&mut dot).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(dot).unwrap(),
+    ///     r#"digraph circuit {
+    ///     "f" [shape=larrow, label="f\n492"];
+    ///     "x" -> "f";
+    ///     "f:1" [shape=plaintext, label="2"];
+    ///     "f:1" -> "f";
+    ///     "x" [shape=ellipse, label="x\n123"];
+    ///     "x:0" [shape=plaintext, label="123"];
+    ///     "x:0" -> "x";
+    /// }
+    /// "#
+    /// );
+    /// ```
+    pub fn write_dot<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut wires: Vec<(&String, &Gate)> = self.wires.iter().collect();
+        wires.sort_by_key(|&(wire, _)| wire);
+
+        writeln!(writer, "digraph circuit {{")?;
+        for (wire, gate) in wires {
+            match self.signal(wire) {
+                Some(signal) => writeln!(
+                    writer,
+                    "    \"{}\" [shape={}, label=\"{}\\n{}\"];",
+                    wire,
+                    gate.shape(),
+                    wire,
+                    signal
+                )?,
+                None => writeln!(writer, "    \"{}\" [shape={}];", wire, gate.shape())?,
+            }
+
+            for (i, signal) in gate.signals().iter().enumerate() {
+                match signal {
+                    Signal::Wire(input) => writeln!(writer, "    \"{}\" -> \"{}\";", input, wire)?,
+                    Signal::Source(value) => {
+                        writeln!(
+                            writer,
+                            "    \"{}:{}\" [shape=plaintext, label=\"{}\"];",
+                            wire, i, value
+                        )?;
+                        writeln!(writer, "    \"{}:{}\" -> \"{}\";", wire, i, wire)?;
+                    }
+                }
+            }
+        }
+        writeln!(writer, "}}")
+    }
+
+    /// Clears the signals of the wire and every wire downstream of it.
+    fn invalidate(&mut self, wire: &str) {
+        let mut stack = vec![String::from(wire)];
+        while let Some(wire) = stack.pop() {
+            if self.signals.remove(&wire).is_none() {
+                continue;
+            }
+            if let Some(dependents) = self.dependents.get(&wire) {
+                stack.extend(dependents.iter().cloned());
+            }
+        }
     }
 
-    fn resolve_wire(&self, name: &str) -> u16 {
-        if self.signals.borrow().contains_key(name) {
-            return *self.signals.borrow().get(name).unwrap();
+    /// Resolves the signal of every wire in the circuit.
+    ///
+    /// Wires are resolved iteratively in topological order, so arbitrarily long chains of wires can be resolved.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a gate takes its input from a wire without a signal, or if the wires form a loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_07::{Circuit, CircuitError, CircuitInstruction};
+    ///
+    /// let mut circuit = Circuit::new();
+    /// circuit.add_instruction(CircuitInstruction::from_str("123 -> x").unwrap());
+    /// circuit.add_instruction(CircuitInstruction::from_str("x AND y -> z").unwrap());
+    /// assert_eq!(
+    ///     circuit.resolve(),
+    ///     Err(CircuitError::UndefinedWire { wire: String::from("y"), input_of: String::from("z") })
+    /// );
+    ///
+    /// circuit.add_instruction(CircuitInstruction::from_str("NOT x -> y").unwrap());
+    /// circuit.resolve().unwrap();
+    /// assert_eq!(circuit.signal("z"), Some(0));
+    /// ```
+    pub fn resolve(&mut self) -> Result<(), CircuitError> {
+        let mut wires: Vec<&String> = self.wires.keys().collect();
+        wires.sort();
+        for wire in wires {
+            if !self.signals.contains_key(wire) {
+                Circuit::resolve_wire(&self.wires, &mut self.signals, wire)?;
+            }
         }
-        let signal = match self.wires.get(name).unwrap() {
-            Gate::And(v1, v2) => self.resolve_signal(v1) & self.resolve_signal(v2),
-            Gate::Or(v1, v2) => self.resolve_signal(v1) | self.resolve_signal(v2),
-            Gate::LeftShift(v1, v2) => self.resolve_signal(v1) << self.resolve_signal(v2),
-            Gate::RightShift(v1, v2) => self.resolve_signal(v1) >> self.resolve_signal(v2),
-            Gate::Not(v1) => !self.resolve_signal(v1),
-            Gate::NoOp(v1) => self.resolve_signal(v1),
+        Ok(())
+    }
+
+    pub fn reset(&mut self) {
+        self.signals.clear();
+    }
+
+    /// Simplifies the circuit without changing the signals of the provided output wires.
+    ///
+    /// Gates whose inputs are all constant are folded into the constant they provide,
+    /// inputs connected through a chain of direct connections are rewired to the start of the chain,
+    /// and every wire that no longer feeds an output is removed.
+    /// Outputs without a gate connected are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a wire feeding an output takes its input from a wire without a signal, or if the wires form a loop.
+    /// The circuit is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_07::{Circuit, Optimization};
+    ///
+    /// let mut circuit = Circuit::from_str("123 -> x\n456 -> y\nx AND y -> d\nd -> e\nNOT y -> i").unwrap();
+    /// let optimization = circuit.optimize(&["e"]).unwrap();
+    /// assert_eq!(optimization, Optimization { folded: 1, collapsed: 3, removed: 4 });
+    /// assert_eq!(circuit.to_string(), "72 -> e\n");
+    /// ```
+    pub fn optimize(&mut self, outputs: &[&str]) -> Result<Optimization, CircuitError> {
+        let mut optimization = Optimization::default();
+
+        let order = {
+            let mut order: Vec<String> = Vec::new();
+            let mut seen = HashSet::new();
+            for &output in outputs
+                .iter()
+                .filter(|&&output| self.wires.contains_key(output))
+            {
+                for wire in topological_order(&self.wires, output, |wire| seen.contains(wire))? {
+                    seen.insert(wire);
+                    order.push(String::from(wire));
+                }
+            }
+            order
         };
-        self.signals.borrow_mut().insert(String::from(name), signal);
-        signal
+
+        let mut gates: HashMap<String, Gate> = HashMap::new();
+        for wire in order {
+            let mut gate = self.wires[&wire].clone();
+            for signal in gate.signals_mut() {
+                if let Signal::Wire(input) = signal {
+                    if let Some(Gate::NoOp(source)) = gates.get(input.as_str()) {
+                        *signal = source.clone();
+                        optimization.collapsed += 1;
+                    }
+                }
+            }
+            if gate.inputs().next().is_none() {
+                let folded = Gate::NoOp(Signal::Source(gate.evaluate(|_| unreachable!())));
+                if folded != gate {
+                    optimization.folded += 1;
+                    gate = folded;
+                }
+            }
+            gates.insert(wire, gate);
+        }
+
+        let mut live = HashSet::new();
+        let mut stack: Vec<&str> = outputs
+            .iter()
+            .copied()
+            .filter(|&output| gates.contains_key(output))
+            .collect();
+        while let Some(wire) = stack.pop() {
+            if live.insert(String::from(wire)) {
+                stack.extend(gates[wire].inputs());
+            }
+        }
+
+        optimization.removed = self.wires.len() - live.len();
+        let signals = std::mem::take(&mut self.signals);
+        *self = gates
+            .into_iter()
+            .filter(|(wire, _)| live.contains(wire))
+            .map(|(wire, output)| CircuitInstruction { wire, output })
+            .collect();
+        self.signals = signals
+            .into_iter()
+            .filter(|(wire, _)| self.wires.contains_key(wire))
+            .collect();
+        Ok(optimization)
     }
 
-    fn resolve_signal(&self, signal: &Signal) -> u16 {
-        match signal {
-            Signal::Source(value) => *value,
-            Signal::Wire(name) => self.resolve_wire(name),
+    /// Finds every set of signals which, provided to the input wires in order, gives the output wire the target signal.
+    ///
+    /// The gates connected to the input wires are ignored, so the input wires do not need to be connected.
+    /// A single input wire is solved by trying every signal.
+    /// More input wires are solved one bit at a time, tracking which bits of every wire are already known
+    /// and abandoning any partial set of signals that already gives a known bit of the output the wrong value.
+    /// An input wire the output does not depend on takes every signal in turn, so there can be very many solutions.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a wire feeding the output takes its input from a wire without a signal, or if the wires form a loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_07::Circuit;
+    ///
+    /// let circuit = Circuit::from_str("x AND 255 -> y\ny LSHIFT 8 -> a").unwrap();
+    /// let solutions = circuit.solve(&["x"], "a", 0x3400).unwrap();
+    /// assert_eq!(solutions.len(), 256);
+    /// assert!(solutions.iter().all(|signals| signals[0] & 0xff == 0x34));
+    ///
+    /// let circuit = Circuit::from_str("x AND y -> a").unwrap();
+    /// assert_eq!(circuit.solve(&["x", "y"], "a", 0xffff).unwrap(), vec![vec![0xffff, 0xffff]]);
+    /// ```
+    pub fn solve(
+        &self,
+        inputs: &[&str],
+        output: &str,
+        target: u16,
+    ) -> Result<Vec<Vec<u16>>, CircuitError> {
+        let mut solutions = Vec::new();
+        self.search(inputs, output, target, |signals| {
+            solutions.push(signals.to_vec());
+            true
+        })?;
+        Ok(solutions)
+    }
+
+    /// Finds the first set of signals which, provided to the input wires in order, gives the output wire the target signal.
+    /// If no signals give the output wire the target signal `None` is returned.
+    ///
+    /// Solutions are found in the same way as [`Circuit::solve`], stopping at the first.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a wire feeding the output takes its input from a wire without a signal, or if the wires form a loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_07::Circuit;
+    ///
+    /// let circuit = Circuit::from_str("NOT b -> a").unwrap();
+    /// assert_eq!(circuit.solve_first(&["b"], "a", 65079).unwrap(), Some(vec![456]));
+    ///
+    /// let circuit = Circuit::from_str("x LSHIFT 1 -> a").unwrap();
+    /// assert_eq!(circuit.solve_first(&["x"], "a", 1).unwrap(), None);
+    /// ```
+    pub fn solve_first(
+        &self,
+        inputs: &[&str],
+        output: &str,
+        target: u16,
+    ) -> Result<Option<Vec<u16>>, CircuitError> {
+        let mut solution = None;
+        self.search(inputs, output, target, |signals| {
+            solution = Some(signals.to_vec());
+            false
+        })?;
+        Ok(solution)
+    }
+
+    /// Calls the provided function with every set of input signals giving the output the target signal,
+    /// until it returns `false`.
+    fn search<F>(
+        &self,
+        inputs: &[&str],
+        output: &str,
+        target: u16,
+        mut found: F,
+    ) -> Result<(), CircuitError>
+    where
+        F: FnMut(&[u16]) -> bool,
+    {
+        let is_input = |wire: &str| inputs.contains(&wire);
+        if !is_input(output) && !self.wires.contains_key(output) {
+            return Ok(());
+        }
+        let order = topological_order(&self.wires, output, is_input)?;
+        let cone = Cone::new(&self.wires, inputs, &order, output);
+
+        if let [_] = inputs {
+            for signal in 0..=u16::MAX {
+                if cone.evaluate(&[Bits::known(signal)]) == Bits::known(target) && !found(&[signal])
+                {
+                    break;
+                }
+            }
+            return Ok(());
+        }
+
+        let mut bits = vec![Bits::UNKNOWN; inputs.len()];
+        cone.search(&mut bits, 0, target, &mut found);
+        Ok(())
+    }
+
+    /// Resolves the signal of the wire, and every wire it depends on.
+    fn resolve_wire(
+        wires: &HashMap<String, Gate>,
+        signals: &mut HashMap<String, u16>,
+        wire: &str,
+    ) -> Result<(), CircuitError> {
+        for wire in topological_order(wires, wire, |wire| signals.contains_key(wire))? {
+            let signal = wires[wire].evaluate(|input| signals[input]);
+            signals.insert(String::from(wire), signal);
         }
+        Ok(())
     }
 }
 
+/// Represents a signal where only some of the bits may be known.
+/// Every bit of the value which is not known is zero.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Bits {
+    known: u16,
+    value: u16,
+}
+
+impl Bits {
+    const UNKNOWN: Bits = Bits { known: 0, value: 0 };
+
+    fn known(value: u16) -> Bits {
+        Bits {
+            known: u16::MAX,
+            value,
+        }
+    }
+
+    fn not(self) -> Bits {
+        Bits {
+            known: self.known,
+            value: !self.value & self.known,
+        }
+    }
+
+    /// A bit is known if both input bits are known, or either is known to be zero.
+    fn and(self, other: Bits) -> Bits {
+        let zeros = (self.known & !self.value) | (other.known & !other.value);
+        let known = (self.known & other.known) | zeros;
+        Bits {
+            known,
+            value: self.value & other.value & known,
+        }
+    }
+
+    /// A bit is known if both input bits are known, or either is known to be one.
+    fn or(self, other: Bits) -> Bits {
+        let known = (self.known & other.known) | self.value | other.value;
+        Bits {
+            known,
+            value: self.value | other.value,
+        }
+    }
+
+    /// Nothing is known of a shift by an unknown number of bits.
+    fn shift<F>(self, amount: Bits, shift: F) -> Bits
+    where
+        F: Fn(u16, u32) -> u16,
+    {
+        if amount.known != u16::MAX {
+            return Bits::UNKNOWN;
+        }
+        let amount = u32::from(amount.value);
+        Bits {
+            known: shift(self.known, amount) | !shift(u16::MAX, amount),
+            value: shift(self.value, amount),
+        }
+    }
+}
+
+/// Represents an input of a gate in a [`Cone`], either a constant or the index of an earlier slot.
+#[derive(Clone, Copy, Debug)]
+enum Operand {
+    Value(u16),
+    Slot(usize),
+}
+
+/// Represents a gate in a [`Cone`], as the function combining the bits of its inputs and the inputs themselves.
+type ConeGate = (fn(&[Bits]) -> Bits, Operand, Operand);
+
+/// Represents the gates feeding an output wire, compiled to be evaluated many times.
+///
+/// The input wires take the first slots, followed by every gate in topological order.
+struct Cone {
+    inputs: usize,
+    gates: Vec<ConeGate>,
+    output: usize,
+}
+
+impl Cone {
+    fn new(wires: &HashMap<String, Gate>, inputs: &[&str], order: &[&str], output: &str) -> Cone {
+        let mut slots: HashMap<&str, usize> = HashMap::new();
+        inputs.iter().enumerate().for_each(|(i, &input)| {
+            slots.entry(input).or_insert(i);
+        });
+
+        let mut gates = Vec::with_capacity(order.len());
+        for &wire in order {
+            let operand = |signal: &Signal| match signal {
+                Signal::Source(value) => Operand::Value(*value),
+                Signal::Wire(wire) => Operand::Slot(slots[wire.as_str()]),
+            };
+            let gate: ConeGate = match &wires[wire] {
+                Gate::NoOp(v1) => (|b| b[0], operand(v1), Operand::Value(0)),
+                Gate::Not(v1) => (|b| b[0].not(), operand(v1), Operand::Value(0)),
+                Gate::And(v1, v2) => (|b| b[0].and(b[1]), operand(v1), operand(v2)),
+                Gate::Or(v1, v2) => (|b| b[0].or(b[1]), operand(v1), operand(v2)),
+                Gate::LeftShift(v1, v2) => (
+                    |b| b[0].shift(b[1], |x, n| x.checked_shl(n).unwrap_or(0)),
+                    operand(v1),
+                    operand(v2),
+                ),
+                Gate::RightShift(v1, v2) => (
+                    |b| b[0].shift(b[1], |x, n| x.checked_shr(n).unwrap_or(0)),
+                    operand(v1),
+                    operand(v2),
+                ),
+            };
+            slots.insert(wire, inputs.len() + gates.len());
+            gates.push(gate);
+        }
+
+        Cone {
+            inputs: inputs.len(),
+            gates,
+            output: slots[output],
+        }
+    }
+
+    /// Returns the bits of the output known from the provided bits of the inputs.
+    fn evaluate(&self, inputs: &[Bits]) -> Bits {
+        let mut slots = Vec::with_capacity(self.inputs + self.gates.len());
+        slots.extend_from_slice(inputs);
+        for (gate, lhs, rhs) in &self.gates {
+            let operand = |operand: &Operand| match operand {
+                Operand::Value(value) => Bits::known(*value),
+                Operand::Slot(slot) => slots[*slot],
+            };
+            let bits = gate(&[operand(lhs), operand(rhs)]);
+            slots.push(bits);
+        }
+        slots[self.output]
+    }
+
+    /// Decides the unknown bits of the inputs from the most significant bit of the first input onwards,
+    /// abandoning any choice which gives a known bit of the output the wrong value.
+    /// Returns `false` once the provided function does.
+    fn search<F>(&self, inputs: &mut [Bits], next: usize, target: u16, found: &mut F) -> bool
+    where
+        F: FnMut(&[u16]) -> bool,
+    {
+        let output = self.evaluate(inputs);
+        if output.known & (output.value ^ Bits::known(target).value) != 0 {
+            return true;
+        }
+        if next == inputs.len() * 16 {
+            let signals: Vec<u16> = inputs.iter().map(|bits| bits.value).collect();
+            return found(&signals);
+        }
+
+        let (input, bit) = (next / 16, 1 << (15 - next % 16));
+        let saved = inputs[input];
+        for &value in &[0, bit] {
+            inputs[input] = Bits {
+                known: saved.known | bit,
+                value: saved.value | value,
+            };
+            if !self.search(inputs, next + 1, target, found) {
+                inputs[input] = saved;
+                return false;
+            }
+        }
+        inputs[input] = saved;
+        true
+    }
+}
+
+/// Returns the wire, and every wire it depends on, ordered so each wire comes after its inputs.
+/// Wires for which `is_done` returns `true` are left out, along with the wires only they depend on.
+///
+/// The order is found with a depth-first search, where each wire is pushed twice:
+/// once to push its inputs, and once more to be ordered after them.
+/// The wires being searched form the path from the starting wire, which closes into a loop if a wire is reached again.
+/// The index of each wire in the path is kept alongside it, so checking for a loop does not scan the path.
+fn topological_order<'a, F>(
+    wires: &'a HashMap<String, Gate>,
+    wire: &'a str,
+    is_done: F,
+) -> Result<Vec<&'a str>, CircuitError>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut order = Vec::new();
+    let mut ordered = HashSet::new();
+    let mut stack = vec![(wire, false)];
+    let mut path: Vec<&str> = Vec::new();
+    let mut path_index: HashMap<&str, usize> = HashMap::new();
+    while let Some((wire, inputs_ordered)) = stack.pop() {
+        if inputs_ordered {
+            ordered.insert(wire);
+            order.push(wire);
+            path.pop();
+            path_index.remove(wire);
+            continue;
+        }
+        if is_done(wire) || ordered.contains(wire) {
+            continue;
+        }
+        if let Some(&start) = path_index.get(wire) {
+            let mut cycle: Vec<String> = path[start..].iter().map(|&w| String::from(w)).collect();
+            cycle.push(String::from(wire));
+            return Err(CircuitError::Cycle(cycle));
+        }
+
+        path_index.insert(wire, path.len());
+        path.push(wire);
+        stack.push((wire, true));
+        for input in wires[wire].inputs() {
+            if is_done(input) {
+                continue;
+            }
+            if !wires.contains_key(input) {
+                return Err(CircuitError::UndefinedWire {
+                    wire: String::from(input),
+                    input_of: String::from(wire),
+                });
+            }
+            stack.push((input, false));
+        }
+    }
+    Ok(order)
+}
+
 impl Default for Circuit {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl FromIterator<CircuitInstruction> for Circuit {
+    fn from_iter<I: IntoIterator<Item = CircuitInstruction>>(iter: I) -> Self {
+        let mut circuit = Circuit::new();
+        iter.into_iter()
+            .for_each(|instruction| circuit.add_instruction(instruction));
+        circuit
+    }
+}
+
+impl Display for Circuit {
+    /// Writes the instructions of the circuit one per line, ordered by wire.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for instruction in self.instructions() {
+            writeln!(f, "{}", instruction)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Circuit {
+    type Err = ParseError;
+
+    /// Parses one instruction per line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(parse_lines::<CircuitInstruction>(s)?.into_iter().collect())
+    }
+}
+
+/// Represents an instruction connecting a gate to a wire.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct CircuitInstruction {
     wire: String,
     output: Gate,
 }
 
+impl CircuitInstruction {
+    /// Constructs a new `CircuitInstruction` connecting the gate to the wire.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the wire, or a wire the gate takes an input from, is not named with one or more lowercase letters,
+    /// as the instruction would not be parsed back from its text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_07::{CircuitInstruction, Gate, Signal};
+    ///
+    /// let instruction = CircuitInstruction::new("d", Gate::Not(Signal::Wire(String::from("x")))).unwrap();
+    /// assert_eq!(instruction.to_string(), "NOT x -> d");
+    ///
+    /// assert!(CircuitInstruction::new("123", Gate::NoOp(Signal::Source(1))).is_err());
+    /// assert!(CircuitInstruction::new("d", Gate::NoOp(Signal::Wire(String::from("AND")))).is_err());
+    /// ```
+    pub fn new(wire: &str, gate: Gate) -> Result<CircuitInstruction, InvalidWireError> {
+        let is_wire = |name: &str| !name.is_empty() && name.bytes().all(|b| b.is_ascii_lowercase());
+        if let Some(name) = Some(wire)
+            .into_iter()
+            .chain(gate.inputs())
+            .find(|name| !is_wire(name))
+        {
+            return Err(InvalidWireError(String::from(name)));
+        }
+
+        Ok(CircuitInstruction {
+            wire: String::from(wire),
+            output: gate,
+        })
+    }
+
+    /// Returns the wire the gate is connected to.
+    #[must_use]
+    pub fn wire(&self) -> &str {
+        &self.wire
+    }
+
+    /// Returns the gate providing the signal of the wire.
+    #[must_use]
+    pub fn gate(&self) -> &Gate {
+        &self.output
+    }
+}
+
+impl Display for CircuitInstruction {
+    /// Writes the instruction in the same format it is parsed from, such as `x AND y -> d`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.output, self.wire)
+    }
+}
+
 impl FromStr for CircuitInstruction {
-    type Err = ();
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<CircuitInstruction, ()> {
+    fn from_str(s: &str) -> Result<CircuitInstruction, ParseError> {
         static BINARY: Lazy<Regex> = Lazy::new(|| {
-            Regex::new(r"^(?P<lhs>\w+|\d+) (?P<operation>AND|OR|LSHIFT|RSHIFT) (?P<rhs>\w+|\d+) -> (?P<wire>\w+)$").unwrap()
+            Regex::new(r"^(?P<lhs>[a-z]+|\d+) (?P<operation>AND|OR|LSHIFT|RSHIFT) (?P<rhs>[a-z]+|\d+) -> (?P<wire>[a-z]+)$").unwrap()
         });
 
         static UNARY: Lazy<Regex> = Lazy::new(|| {
-            Regex::new(r"^(?P<operator>NOT)?\s?(?P<operand>\w+|\d+) -> (?P<wire>\w+)$").unwrap()
+            Regex::new(r"^(?P<operator>NOT)?\s?(?P<operand>[a-z]+|\d+) -> (?P<wire>[a-z]+)$")
+                .unwrap()
         });
 
-        if let Some(caps) = BINARY.captures(s) {
-            let caps = CapturesWrapper::new(caps);
-            let lhs = Signal::from(caps.as_str("lhs"));
-            let rhs = Signal::from(caps.as_str("rhs"));
+        const FORMAT: &str =
+            "{lhs} {AND|OR|LSHIFT|RSHIFT} {rhs} -> {wire}, or [NOT] {operand} -> {wire}";
+
+        if let Ok(caps) = CapturesWrapper::captures(&BINARY, s, FORMAT) {
+            let lhs = Signal::parse(&caps, "lhs")?;
+            let rhs = Signal::parse(&caps, "rhs")?;
             let wire = caps.as_string("wire");
             let output = match caps.as_str("operation") {
                 "AND" => Gate::And(lhs, rhs),
@@ -128,18 +1016,67 @@ impl FromStr for CircuitInstruction {
             return Ok(CircuitInstruction { wire, output });
         };
 
-        if let Some(caps) = UNARY.captures(s) {
-            let caps = CapturesWrapper::new(caps);
-            let wire = caps.as_string("wire");
-            let operand = Signal::from(caps.as_str("operand"));
-            let output = match caps.name("operator") {
-                Some(_) => Gate::Not(operand),
-                None => Gate::NoOp(operand),
-            };
-
-            return Ok(CircuitInstruction { wire, output });
+        let caps = CapturesWrapper::captures(&UNARY, s, FORMAT)?;
+        let wire = caps.as_string("wire");
+        let operand = Signal::parse(&caps, "operand")?;
+        let output = match caps.name("operator") {
+            Some(_) => Gate::Not(operand),
+            None => Gate::NoOp(operand),
         };
 
-        Err(())
+        Ok(CircuitInstruction { wire, output })
+    }
+}
+
+/// Solution to Year 2015 Day 7: Some Assembly Required.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Some Assembly Required";
+
+    type Input = Vec<CircuitInstruction>;
+    type PartOne = u16;
+    type PartTwo = u16;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(parse_lines(input)?)
     }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((parsed.items, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        let mut circuit = Circuit::new();
+        input
+            .iter()
+            .for_each(|instruction| circuit.add_instruction(instruction.clone()));
+        circuit.resolve()?;
+
+        signal_a(&circuit)
+    }
+
+    /// The signal of wire `a` from the first part overrides wire `b`,
+    /// and only the wires downstream of `b` are resolved again; the others keep their signals.
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let mut circuit = Circuit::new();
+        input
+            .iter()
+            .for_each(|instruction| circuit.add_instruction(instruction.clone()));
+        circuit.resolve()?;
+        let signal = signal_a(&circuit)?;
+
+        circuit.override_wire("b", signal)?;
+
+        signal_a(&circuit)
+    }
+}
+
+fn signal_a(circuit: &Circuit) -> SolutionResult<u16> {
+    circuit
+        .signal("a")
+        .ok_or_else(|| "no signal is provided to wire 'a'".into())
 }
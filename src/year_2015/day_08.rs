@@ -1,6 +1,8 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::solution::{Solution, SolutionResult};
+
 pub fn reformat_string(before: &str) -> String {
     static DOUBLE_QUOTES_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r#""(?P<s>\S+)?""#).unwrap());
     static HEX_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\\x[0-9a-fA-F]{2}").unwrap());
@@ -18,3 +20,32 @@ pub fn escape_string(before: &str) -> String {
     let s = ESCAPE_REGEX.replace_all(before, r"\$s");
     DOUBLE_QUOTES_REGEX.replace(&s, r#""$s""#).to_string()
 }
+
+/// Solution to Year 2015 Day 8: Matchsticks.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Matchsticks";
+
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(String::from(input))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        let before: usize = input.lines().map(str::len).sum();
+        let after: usize = input.lines().map(|s| reformat_string(s).len()).sum();
+        Ok(before - after)
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let before: usize = input.lines().map(str::len).sum();
+        let after: usize = input.lines().map(|s| escape_string(s).len()).sum();
+        Ok(after - before)
+    }
+}
@@ -5,7 +5,8 @@ use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use crate::util::CapturesWrapper;
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError};
 
 pub struct Graph {
     vertices: HashSet<String>,
@@ -29,6 +30,12 @@ impl Graph {
         self.edges.insert(edge_key, edge.weight);
     }
 
+    /// Returns `true` if the `Graph` has no locations.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
     fn weight(&self, origin: &str, destination: &str) -> u32 {
         let mut edge_key = [String::from(origin), String::from(destination)];
         edge_key.sort();
@@ -89,25 +96,67 @@ pub struct Edge {
 }
 
 impl FromStr for Edge {
-    type Err = ();
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Edge, ()> {
+    fn from_str(s: &str) -> Result<Edge, ParseError> {
         static REGEX: Lazy<Regex> = Lazy::new(|| {
             Regex::new(r"^(?P<origin>\w+) to (?P<destination>\w+) = (?P<weight>\d+)$").unwrap()
         });
 
-        if let Some(caps) = REGEX.captures(s) {
-            let caps = CapturesWrapper::new(caps);
-            let origin = caps.as_string("origin");
-            let destination = caps.as_string("destination");
-            let weight = caps.parse("weight");
-
-            return Ok(Edge {
-                origin,
-                destination,
-                weight,
-            });
-        };
-        Err(())
+        let caps = CapturesWrapper::captures(&REGEX, s, "{origin} to {destination} = {distance}")?;
+        let origin = caps.as_string("origin");
+        let destination = caps.as_string("destination");
+        let weight = caps.parse("weight")?;
+
+        Ok(Edge {
+            origin,
+            destination,
+            weight,
+        })
+    }
+}
+
+/// Solution to Year 2015 Day 9: All in a Single Night.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "All in a Single Night";
+
+    type Input = Graph;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        let mut graph = Graph::new();
+        parse_lines::<Edge>(input)?
+            .into_iter()
+            .for_each(|edge| graph.add_edge(edge));
+        Ok(graph)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let mut graph = Graph::new();
+        let parsed = parse_lines_lenient::<Edge>(input);
+        parsed
+            .items
+            .into_iter()
+            .for_each(|edge| graph.add_edge(edge));
+        Ok((graph, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        if input.is_empty() {
+            return Err("the graph has no locations".into());
+        }
+        Ok(input.shortest_path())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        if input.is_empty() {
+            return Err("the graph has no locations".into());
+        }
+        Ok(input.longest_path())
     }
 }
@@ -1,3 +1,5 @@
+use crate::solution::{Solution, SolutionResult};
+
 fn get_count(s: &[u8], c: u8) -> usize {
     let mut count = 0;
     for n in s {
@@ -33,3 +35,36 @@ pub fn look_and_say(number: &str) -> String {
     }
     unsafe { String::from_utf8_unchecked(result) }
 }
+
+/// Solution to Year 2015 Day 10: Elves Look, Elves Say.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Elves Look, Elves Say";
+
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(String::from(input.trim()))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        let mut sequence = input.clone();
+        for _ in 0..40 {
+            sequence = look_and_say(&sequence);
+        }
+        Ok(sequence.len())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let mut sequence = input.clone();
+        for _ in 0..50 {
+            sequence = look_and_say(&sequence);
+        }
+        Ok(sequence.len())
+    }
+}
@@ -2,6 +2,8 @@ use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::{fmt, str};
 
+use crate::solution::{Solution, SolutionResult};
+
 fn rotate_letters(letters: &mut [u8]) {
     let mut first = true;
     for letter in letters {
@@ -105,3 +107,30 @@ pub fn next_password(old_password: &str) -> Result<String, NextPasswordError> {
 
     unsafe { Result::Ok(String::from_utf8_unchecked(password)) }
 }
+
+/// Solution to Year 2015 Day 11: Corporate Policy.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Corporate Policy";
+
+    type Input = String;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(String::from(input.trim()))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(next_password(input)?)
+    }
+
+    /// Santa's password expires again, so the next password follows the one found in the first part.
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let password = Day11::part_one(input)?;
+        Ok(next_password(&password)?)
+    }
+}
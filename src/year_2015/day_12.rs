@@ -1,7 +1,12 @@
+use std::io;
+use std::io::{BufRead, ErrorKind};
+
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde_json::Value;
 
+use crate::solution::{Solution, SolutionResult};
+
 #[must_use]
 pub fn sum_numbers_in_str(s: &str) -> i32 {
     static REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"-?\d+").unwrap());
@@ -13,12 +18,81 @@ pub fn sum_numbers_in_str(s: &str) -> i32 {
         .sum()
 }
 
+/// Returns the sum of every number in the document read from the reader.
+///
+/// The document is consumed one buffer at a time, so documents too large to hold in memory can be summed.
+/// Numbers too large to fit in an `i64` are skipped, the same as [`sum_numbers_in_str`] skips numbers too large for an `i32`.
+///
+/// # Errors
+///
+/// Will return `Err` if the reader fails, or if the sum is too large to fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_12::sum_numbers_from_reader;
+///
+/// assert_eq!(sum_numbers_from_reader(&b"[1,2,3]"[..]).unwrap(), 6);
+/// assert_eq!(sum_numbers_from_reader(&br#"{"a":[-1,1]}"#[..]).unwrap(), 0);
+/// assert_eq!(sum_numbers_from_reader(&b"[1,99999999999999999999,2]"[..]).unwrap(), 3);
+/// ```
+pub fn sum_numbers_from_reader<R: BufRead>(mut reader: R) -> io::Result<i64> {
+    let mut sum: i64 = 0;
+    let mut add = |number: Option<i64>| match number {
+        Some(number) => {
+            sum = sum.checked_add(number).ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    "the sum of the numbers is too large",
+                )
+            })?;
+            Ok(sum)
+        }
+        None => Ok(sum),
+    };
+
+    // The number being read, with its sign applied to every digit; `None` once it is too large to fit.
+    let mut number: Option<i64> = Some(0);
+    let mut digits = false;
+    let mut negative = false;
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return add(number.filter(|_| digits));
+        }
+
+        for &c in buffer {
+            if c.is_ascii_digit() {
+                let digit = i64::from(c - b'0');
+                number = number.and_then(|n| n.checked_mul(10)).and_then(|n| {
+                    if negative {
+                        n.checked_sub(digit)
+                    } else {
+                        n.checked_add(digit)
+                    }
+                });
+                digits = true;
+                continue;
+            }
+            if digits {
+                add(number)?;
+                number = Some(0);
+                digits = false;
+            }
+            negative = c == b'-';
+        }
+
+        let length = buffer.len();
+        reader.consume(length);
+    }
+}
+
 #[must_use]
 pub fn sum_value(value: &Value) -> i64 {
     match value {
         Value::Null | Value::Bool(_) | Value::String(_) => 0,
         Value::Number(number) => number.as_i64().unwrap(),
-        Value::Array(vec) => vec.iter().map(|value| sum_value(value)).sum(),
+        Value::Array(vec) => vec.iter().map(sum_value).sum(),
         Value::Object(map) => {
             let red = map.values().any(|value| match value {
                 Value::String(s) => s == "red",
@@ -29,7 +103,33 @@ pub fn sum_value(value: &Value) -> i64 {
                 return 0;
             }
 
-            map.values().map(|value| sum_value(value)).sum()
+            map.values().map(sum_value).sum()
         }
     }
 }
+
+/// Solution to Year 2015 Day 12: JSAbacusFramework.io.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "JSAbacusFramework.io";
+
+    type Input = String;
+    type PartOne = i32;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(String::from(input))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(sum_numbers_in_str(input))
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let value = serde_json::from_str(input)?;
+        Ok(sum_value(&value))
+    }
+}
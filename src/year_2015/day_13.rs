@@ -6,8 +6,10 @@ use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use crate::util::CapturesWrapper;
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError};
 
+#[derive(Clone)]
 pub struct SeatingPlan {
     pub guests: HashSet<String>,
     preferences: HashMap<[String; 2], i32>,
@@ -74,30 +76,86 @@ pub struct SeatingPreference {
 }
 
 impl FromStr for SeatingPreference {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<SeatingPreference, Self::Err> {
         static REGEX: Lazy<Regex> = Lazy::new(|| {
             Regex::new(r"^(?P<guest>\w+) would (?P<operative>gain|lose) (?P<happiness>\d+) happiness units by sitting next to (?P<neighbour>\w+).$").unwrap()
         });
 
-        match REGEX.captures(s) {
-            Some(caps) => {
-                let caps = CapturesWrapper::new(caps);
-                let guest = caps.as_string("guest");
-                let neighbour = caps.as_string("neighbour");
-                let operative_word = caps.as_str("operative");
-                let mut happiness = caps.parse::<i32>("happiness");
-                if operative_word == "lose" {
-                    happiness = happiness.neg();
-                }
-                Ok(SeatingPreference {
-                    guest,
-                    neighbour,
-                    happiness,
-                })
+        let caps = CapturesWrapper::captures(
+            &REGEX,
+            s,
+            "{guest} would {gain|lose} {happiness} happiness units by sitting next to {neighbour}.",
+        )?;
+        let guest = caps.as_string("guest");
+        let neighbour = caps.as_string("neighbour");
+        let operative_word = caps.as_str("operative");
+        let mut happiness = caps.parse::<i32>("happiness")?;
+        if operative_word == "lose" {
+            happiness = happiness.neg();
+        }
+        Ok(SeatingPreference {
+            guest,
+            neighbour,
+            happiness,
+        })
+    }
+}
+
+/// Solution to Year 2015 Day 13: Knights of the Dinner Table.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Knights of the Dinner Table";
+
+    type Input = SeatingPlan;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        let mut plan = SeatingPlan::new();
+        parse_lines::<SeatingPreference>(input)?
+            .into_iter()
+            .for_each(|preference| plan.add_preference(preference));
+        Ok(plan)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let mut plan = SeatingPlan::new();
+        let parsed = parse_lines_lenient::<SeatingPreference>(input);
+        parsed
+            .items
+            .into_iter()
+            .for_each(|preference| plan.add_preference(preference));
+        Ok((plan, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(input.happiest_table())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let mut plan = input.clone();
+        for guest in &input.guests {
+            let s = format!(
+                "You would gain 0 happiness units by sitting next to {}.",
+                guest
+            );
+            if let Ok(preference) = SeatingPreference::from_str(&s) {
+                plan.add_preference(preference);
+            }
+
+            let s = format!(
+                "{} would gain 0 happiness units by sitting next to You.",
+                guest
+            );
+            if let Ok(preference) = SeatingPreference::from_str(&s) {
+                plan.add_preference(preference);
             }
-            None => Err(()),
         }
+        Ok(plan.happiest_table())
     }
 }
@@ -4,7 +4,8 @@ use hashbrown::HashMap;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use crate::util::CapturesWrapper;
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError};
 
 pub struct Reindeer {
     name: String,
@@ -14,30 +15,29 @@ pub struct Reindeer {
 }
 
 impl FromStr for Reindeer {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         static REGEX: Lazy<Regex> = Lazy::new(|| {
             Regex::new(r"^(?P<name>\w+) can fly (?P<flying_speed>\d+) km/s for (?P<flying_time>\d+) seconds, but then must rest for (?P<rest_time>\d+) seconds.$").unwrap()
         });
 
-        match REGEX.captures(s) {
-            Some(caps) => {
-                let caps = CapturesWrapper::new(caps);
-                let name = caps.as_string("name");
-                let flying_speed = caps.parse("flying_speed");
-                let flying_time = caps.parse("flying_time");
-                let rest_time = caps.parse("rest_time");
-
-                Ok(Reindeer {
-                    name,
-                    flying_speed,
-                    flying_time,
-                    rest_time,
-                })
-            }
-            None => Err(()),
-        }
+        let caps = CapturesWrapper::captures(
+            &REGEX,
+            s,
+            "{name} can fly {speed} km/s for {time} seconds, but then must rest for {time} seconds.",
+        )?;
+        let name = caps.as_string("name");
+        let flying_speed = caps.parse("flying_speed")?;
+        let flying_time = caps.parse("flying_time")?;
+        let rest_time = caps.parse("rest_time")?;
+
+        Ok(Reindeer {
+            name,
+            flying_speed,
+            flying_time,
+            rest_time,
+        })
     }
 }
 
@@ -115,7 +115,7 @@ pub fn points_awarded_winning_reindeer(reindeer: &[Reindeer], race_time: u32) ->
     let mut racers: HashMap<String, Racer> = HashMap::new();
     for _ in 0..race_time {
         for deer in reindeer {
-            let mut racer = racers.entry(deer.name.clone()).or_default();
+            let racer = racers.entry(deer.name.clone()).or_default();
             match racer.state {
                 State::Flying(n) => {
                     racer.state = if n == deer.flying_time {
@@ -144,3 +144,39 @@ pub fn points_awarded_winning_reindeer(reindeer: &[Reindeer], race_time: u32) ->
     }
     racers.values().map(|racer| racer.points).max().unwrap()
 }
+
+/// Solution to Year 2015 Day 14: Reindeer Olympics.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Reindeer Olympics";
+
+    type Input = Vec<Reindeer>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((parsed.items, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        if input.is_empty() {
+            return Err("there are no reindeer in the race".into());
+        }
+        Ok(distance_winning_reindeer_traveled(input, 2503))
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        if input.is_empty() {
+            return Err("there are no reindeer in the race".into());
+        }
+        Ok(points_awarded_winning_reindeer(input, 2503))
+    }
+}
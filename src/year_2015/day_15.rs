@@ -1,44 +1,247 @@
+/*!
+--- Day 15: Science for Hungry People ---
+
+Today, you set out on the task of perfecting your milk-dunking cookie recipe.
+All you have to do is find the right balance of ingredients.
+*/
+
+use std::str::FromStr;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::str::FromStr;
 
-use crate::util::CapturesWrapper;
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError};
 
-struct Ingredient {
-    name: String,
-    capacity: i32,
-    durability: i32,
-    flavor: i32,
-    texture: i32,
-    calories: i32,
+/// Represents the properties of an ingredient per teaspoon.
+#[derive(Clone, Debug)]
+pub struct Ingredient {
+    pub name: String,
+    pub capacity: i32,
+    pub durability: i32,
+    pub flavor: i32,
+    pub texture: i32,
+    pub calories: i32,
 }
 
 impl FromStr for Ingredient {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        static REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(?P<name>\w+): capacity (?P<capacity>-?\d+), durability (?P<durability>-?\d+), flavor (?P<flavor>-?\d+), texture (?P<texture>-?\d+), calories (?P<calories>-?\d+)$").unwrap());
-
-        match REGEX.captures(s) {
-            Some(caps) => {
-                let caps = CapturesWrapper::new(caps);
-                let name = caps.as_string("name");
-                let capacity = caps.parse("capacity");
-                let durability = caps.parse("durability");
-                let flavor = caps.parse("flavor");
-                let texture = caps.parse("texture");
-                let calories = caps.parse("calories");
-
-                Ok(Ingredient {
-                    name,
-                    capacity,
-                    durability,
-                    flavor,
-                    texture,
-                    calories,
-                })
-            }
-            None => Err(()),
+        static REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^(?P<name>\w+): capacity (?P<capacity>-?\d+), durability (?P<durability>-?\d+), flavor (?P<flavor>-?\d+), texture (?P<texture>-?\d+), calories (?P<calories>-?\d+)$").unwrap()
+        });
+
+        let caps = CapturesWrapper::captures(
+            &REGEX,
+            s,
+            "{name}: capacity {capacity}, durability {durability}, flavor {flavor}, texture {texture}, calories {calories}",
+        )?;
+        let name = caps.as_string("name");
+        let capacity = caps.parse("capacity")?;
+        let durability = caps.parse("durability")?;
+        let flavor = caps.parse("flavor")?;
+        let texture = caps.parse("texture")?;
+        let calories = caps.parse("calories")?;
+
+        Ok(Ingredient {
+            name,
+            capacity,
+            durability,
+            flavor,
+            texture,
+            calories,
+        })
+    }
+}
+
+/// Represents the ingredients available for a cookie recipe.
+pub struct Recipe {
+    ingredients: Vec<Ingredient>,
+}
+
+impl Recipe {
+    /// Constructs a new `Recipe` with the provided ingredients.
+    #[must_use]
+    pub fn new(ingredients: Vec<Ingredient>) -> Recipe {
+        Recipe { ingredients }
+    }
+
+    /// Returns the ingredients of the recipe.
+    #[must_use]
+    pub fn ingredients(&self) -> &[Ingredient] {
+        &self.ingredients
+    }
+
+    /// Calculates the score of a cookie made with the provided teaspoons of each ingredient, in order.
+    ///
+    /// The score is the product of the capacity, durability, flavor, and texture totals, where a negative total becomes zero.
+    /// Calories are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_15::{Ingredient, Recipe};
+    ///
+    /// let recipe = Recipe::new(vec![
+    ///     Ingredient::from_str("Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8").unwrap(),
+    ///     Ingredient::from_str("Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3").unwrap(),
+    /// ]);
+    /// assert_eq!(recipe.score(&[44, 56]), 62842880);
+    /// ```
+    #[must_use]
+    pub fn score(&self, teaspoons: &[u32]) -> i64 {
+        let total = |property: fn(&Ingredient) -> i32| {
+            let total: i64 = self
+                .ingredients
+                .iter()
+                .zip(teaspoons)
+                .map(|(ingredient, &amount)| i64::from(property(ingredient)) * i64::from(amount))
+                .sum();
+            total.max(0)
+        };
+
+        total(|ingredient| ingredient.capacity)
+            * total(|ingredient| ingredient.durability)
+            * total(|ingredient| ingredient.flavor)
+            * total(|ingredient| ingredient.texture)
+    }
+
+    /// Calculates the calories of a cookie made with the provided teaspoons of each ingredient, in order.
+    #[must_use]
+    pub fn calories(&self, teaspoons: &[u32]) -> i64 {
+        self.ingredients
+            .iter()
+            .zip(teaspoons)
+            .map(|(ingredient, &amount)| i64::from(ingredient.calories) * i64::from(amount))
+            .sum()
+    }
+
+    /// Finds the highest score of any cookie using exactly the provided total teaspoons of ingredients.
+    /// If the recipe has no ingredients `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_15::{Ingredient, Recipe};
+    ///
+    /// let recipe = Recipe::new(vec![
+    ///     Ingredient::from_str("Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8").unwrap(),
+    ///     Ingredient::from_str("Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3").unwrap(),
+    /// ]);
+    /// assert_eq!(recipe.highest_score(100), Some(62842880));
+    /// ```
+    #[must_use]
+    pub fn highest_score(&self, total_teaspoons: u32) -> Option<i64> {
+        self.best_allocation(total_teaspoons, |_| true)
+            .map(|teaspoons| self.score(&teaspoons))
+    }
+
+    /// Finds the highest score of any cookie using exactly the provided total teaspoons of ingredients,
+    /// with exactly the provided calories.
+    /// If no cookie has exactly the provided calories `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_15::{Ingredient, Recipe};
+    ///
+    /// let recipe = Recipe::new(vec![
+    ///     Ingredient::from_str("Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8").unwrap(),
+    ///     Ingredient::from_str("Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3").unwrap(),
+    /// ]);
+    /// assert_eq!(recipe.highest_score_with_calories(100, 500), Some(57600000));
+    /// assert_eq!(recipe.highest_score_with_calories(100, 1), None);
+    /// ```
+    #[must_use]
+    pub fn highest_score_with_calories(&self, total_teaspoons: u32, calories: i64) -> Option<i64> {
+        self.best_allocation(total_teaspoons, |teaspoons| {
+            self.calories(teaspoons) == calories
+        })
+        .map(|teaspoons| self.score(&teaspoons))
+    }
+
+    /// Returns the teaspoons of each ingredient with the highest score out of every allocation accepted by the filter.
+    fn best_allocation<F>(&self, total_teaspoons: u32, filter: F) -> Option<Vec<u32>>
+    where
+        F: Fn(&[u32]) -> bool,
+    {
+        if self.ingredients.is_empty() {
+            return None;
         }
+
+        let mut best: Option<(i64, Vec<u32>)> = None;
+        let mut teaspoons = vec![0; self.ingredients.len()];
+        for_each_allocation(&mut teaspoons, 0, total_teaspoons, &mut |teaspoons| {
+            if !filter(teaspoons) {
+                return;
+            }
+            let score = self.score(teaspoons);
+            if best
+                .as_ref()
+                .is_none_or(|(best_score, _)| score > *best_score)
+            {
+                best = Some((score, teaspoons.to_vec()));
+            }
+        });
+        best.map(|(_, teaspoons)| teaspoons)
+    }
+}
+
+/// Calls the provided function with every way of splitting the remaining teaspoons between the ingredients from index onwards.
+fn for_each_allocation<F>(teaspoons: &mut [u32], index: usize, remaining: u32, f: &mut F)
+where
+    F: FnMut(&[u32]),
+{
+    if index == teaspoons.len() - 1 {
+        teaspoons[index] = remaining;
+        f(teaspoons);
+        return;
+    }
+
+    for amount in 0..=remaining {
+        teaspoons[index] = amount;
+        for_each_allocation(teaspoons, index + 1, remaining - amount, f);
+    }
+}
+
+/// Solution to Year 2015 Day 15: Science for Hungry People.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Science for Hungry People";
+
+    type Input = Recipe;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        let ingredients = parse_lines(input)?;
+        Ok(Recipe::new(ingredients))
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((Recipe::new(parsed.items), parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        input
+            .highest_score(100)
+            .ok_or_else(|| "the recipe has no ingredients".into())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        input
+            .highest_score_with_calories(100, 500)
+            .ok_or_else(|| "no cookie has exactly 500 calories".into())
     }
 }
@@ -0,0 +1,178 @@
+/*!
+--- Day 16: Aunt Sue ---
+
+Your Aunt Sue has given you a wonderful gift, and you'd like to send her a thank you card.
+However, there's a small problem: she signed it "From, Aunt Sue".
+
+You have 500 Aunts named "Sue".
+*/
+
+use std::str::FromStr;
+
+use hashbrown::HashMap;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError};
+
+/// The compounds detected by the My First Crime Scene Analysis Machine on the gift.
+pub const TICKER_TAPE: [(&str, u32); 10] = [
+    ("children", 3),
+    ("cats", 7),
+    ("samoyeds", 2),
+    ("pomeranians", 3),
+    ("akitas", 0),
+    ("vizslas", 0),
+    ("goldfish", 5),
+    ("trees", 3),
+    ("cars", 2),
+    ("perfumes", 1),
+];
+
+/// Represents the things you remember about an Aunt Sue.
+#[derive(Clone, Debug)]
+pub struct Sue {
+    pub number: u32,
+    compounds: HashMap<String, u32>,
+}
+
+impl Sue {
+    /// Returns the remembered amount of the provided compound.
+    /// If the amount is not remembered `None` is returned.
+    #[must_use]
+    pub fn compound(&self, name: &str) -> Option<u32> {
+        self.compounds.get(name).copied()
+    }
+
+    /// Returns `true` if every remembered compound is equal to the reading on the ticker tape; `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_16::Sue;
+    ///
+    /// let sue = Sue::from_str("Sue 1: cats: 7, trees: 3, cars: 2").unwrap();
+    /// assert!(sue.matches_exactly());
+    ///
+    /// let sue = Sue::from_str("Sue 2: cats: 8, trees: 3, cars: 2").unwrap();
+    /// assert!(!sue.matches_exactly());
+    /// ```
+    #[must_use]
+    pub fn matches_exactly(&self) -> bool {
+        self.matches(|_, remembered, reading| remembered == reading)
+    }
+
+    /// Returns `true` if every remembered compound is consistent with the reading on the ticker tape,
+    /// accounting for the outdated retroencabulator; `false` otherwise.
+    ///
+    /// The cats and trees readings indicate that there are greater than that many,
+    /// and the pomeranians and goldfish readings indicate that there are fewer than that many.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_16::Sue;
+    ///
+    /// let sue = Sue::from_str("Sue 1: cats: 8, goldfish: 4, cars: 2").unwrap();
+    /// assert!(sue.matches_ranges());
+    ///
+    /// let sue = Sue::from_str("Sue 2: cats: 7, goldfish: 4, cars: 2").unwrap();
+    /// assert!(!sue.matches_ranges());
+    /// ```
+    #[must_use]
+    pub fn matches_ranges(&self) -> bool {
+        self.matches(|name, remembered, reading| match name {
+            "cats" | "trees" => remembered > reading,
+            "pomeranians" | "goldfish" => remembered < reading,
+            _ => remembered == reading,
+        })
+    }
+
+    fn matches<F>(&self, compare: F) -> bool
+    where
+        F: Fn(&str, u32, u32) -> bool,
+    {
+        TICKER_TAPE
+            .iter()
+            .all(|&(name, reading)| match self.compound(name) {
+                Some(remembered) => compare(name, remembered, reading),
+                None => true,
+            })
+    }
+}
+
+impl FromStr for Sue {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^Sue (?P<number>\d+): (?P<compounds>\w+: \d+(?:, \w+: \d+)*)$").unwrap()
+        });
+
+        let caps = CapturesWrapper::captures(&REGEX, s, "Sue {number}: {compound}: {amount}, ...")?;
+        let number = caps.parse("number")?;
+
+        let mut compounds = HashMap::new();
+        for compound in caps.as_str("compounds").split(", ") {
+            if let Some((name, amount)) = compound.split_once(": ") {
+                let amount = amount.parse().map_err(|err| caps.error(err))?;
+                compounds.insert(String::from(name), amount);
+            }
+        }
+
+        Ok(Sue { number, compounds })
+    }
+}
+
+/// Returns the number of the first Aunt Sue matching the ticker tape exactly.
+/// If no Aunt Sue matches `None` is returned.
+#[must_use]
+pub fn find_sue(sues: &[Sue]) -> Option<u32> {
+    sues.iter()
+        .find(|sue| sue.matches_exactly())
+        .map(|sue| sue.number)
+}
+
+/// Returns the number of the first Aunt Sue matching the ranges of the ticker tape.
+/// If no Aunt Sue matches `None` is returned.
+#[must_use]
+pub fn find_real_sue(sues: &[Sue]) -> Option<u32> {
+    sues.iter()
+        .find(|sue| sue.matches_ranges())
+        .map(|sue| sue.number)
+}
+
+/// Solution to Year 2015 Day 16: Aunt Sue.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Aunt Sue";
+
+    type Input = Vec<Sue>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((parsed.items, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        find_sue(input).ok_or_else(|| "no Aunt Sue matches the ticker tape".into())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        find_real_sue(input).ok_or_else(|| "no Aunt Sue matches the ticker tape".into())
+    }
+}
@@ -0,0 +1,97 @@
+/*!
+--- Day 17: No Such Thing as Too Much ---
+
+The elves bought too much eggnog again - 150 liters this time.
+To fit it all into your refrigerator, you'll need to move it into smaller containers.
+*/
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, ParseError};
+
+/// Counts the combinations of containers, by the number of containers used, that exactly fit the provided liters.
+///
+/// The count at index `n` is the number of combinations using exactly `n` containers.
+/// Containers with the same size are still different containers.
+fn combinations_by_size(containers: &[u32], liters: u32) -> Vec<Vec<usize>> {
+    let liters = liters as usize;
+    let mut counts = vec![vec![0; liters + 1]; containers.len() + 1];
+    counts[0][0] = 1;
+
+    for (i, &container) in containers.iter().enumerate() {
+        let container = container as usize;
+        for used in (0..=i).rev() {
+            for total in (container..=liters).rev() {
+                counts[used + 1][total] += counts[used][total - container];
+            }
+        }
+    }
+    counts
+}
+
+/// Returns the number of different combinations of containers that can exactly fit the provided liters.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_17::combinations;
+///
+/// let count = combinations(&[20, 15, 10, 5, 5], 25);
+/// assert_eq!(count, 4);
+/// ```
+#[must_use]
+pub fn combinations(containers: &[u32], liters: u32) -> usize {
+    combinations_by_size(containers, liters)
+        .iter()
+        .map(|counts| counts[liters as usize])
+        .sum()
+}
+
+/// Returns the number of different combinations of containers that can exactly fit the provided liters,
+/// using the minimum number of containers.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_17::minimum_combinations;
+///
+/// let count = minimum_combinations(&[20, 15, 10, 5, 5], 25);
+/// assert_eq!(count, 3);
+/// ```
+#[must_use]
+pub fn minimum_combinations(containers: &[u32], liters: u32) -> usize {
+    combinations_by_size(containers, liters)
+        .iter()
+        .map(|counts| counts[liters as usize])
+        .find(|&count| count != 0)
+        .unwrap_or(0)
+}
+
+/// Solution to Year 2015 Day 17: No Such Thing as Too Much.
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "No Such Thing as Too Much";
+
+    type Input = Vec<u32>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((parsed.items, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(combinations(input, 150))
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        Ok(minimum_combinations(input, 150))
+    }
+}
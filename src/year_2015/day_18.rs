@@ -0,0 +1,210 @@
+/*!
+--- Day 18: Like a GIF For Your Yard ---
+
+After the million lights incident, the fire code has gotten stricter: now, at most ten thousand lights are allowed.
+You arrange them in a 100x100 grid.
+
+Never one to let you down, Santa again mails you instructions on the ideal lighting configuration.
+With so few lights, he says, you'll have to resort to animation.
+*/
+
+use std::str::FromStr;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::ParseError;
+
+/// Represents a square grid of lights animated like Conway's Game of Life.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Animation {
+    size: usize,
+    lights: Vec<bool>,
+    stuck_corners: bool,
+}
+
+impl Animation {
+    /// Returns the number of lights on each side of the grid.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the light at the provided position is on; `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the position is outside of the grid.
+    #[must_use]
+    pub fn is_on(&self, x: usize, y: usize) -> bool {
+        assert!(
+            x < self.size && y < self.size,
+            "position is outside of the grid"
+        );
+        self.lights[y * self.size + x]
+    }
+
+    /// Returns the number of lights that are on.
+    #[must_use]
+    pub fn lights_on(&self) -> usize {
+        self.lights.iter().filter(|&&light| light).count()
+    }
+
+    /// Turns on the four corner lights and keeps them stuck on in every following step.
+    pub fn stick_corners(&mut self) {
+        self.stuck_corners = true;
+        self.turn_on_corners();
+    }
+
+    fn turn_on_corners(&mut self) {
+        if self.size == 0 {
+            return;
+        }
+        let last = self.size - 1;
+        for &(x, y) in &[(0, 0), (last, 0), (0, last), (last, last)] {
+            self.lights[y * self.size + x] = true;
+        }
+    }
+
+    fn neighbours_on(&self, x: usize, y: usize) -> usize {
+        let mut count = 0;
+        for ny in y.saturating_sub(1)..=(y + 1).min(self.size - 1) {
+            for nx in x.saturating_sub(1)..=(x + 1).min(self.size - 1) {
+                if (nx, ny) != (x, y) && self.lights[ny * self.size + nx] {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Animates the grid by one step.
+    ///
+    /// A light which is on stays on when 2 or 3 neighbours are on, and turns off otherwise.
+    /// A light which is off turns on if exactly 3 neighbours are on, and stays off otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_18::Animation;
+    ///
+    /// let mut animation = Animation::from_str(".#.\n.#.\n.#.").unwrap();
+    /// animation.step();
+    /// assert_eq!(animation, Animation::from_str("...\n###\n...").unwrap());
+    /// ```
+    pub fn step(&mut self) {
+        let mut lights = Vec::with_capacity(self.lights.len());
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let light = matches!(
+                    (self.lights[y * self.size + x], self.neighbours_on(x, y)),
+                    (true, 2) | (_, 3)
+                );
+                lights.push(light);
+            }
+        }
+        self.lights = lights;
+
+        if self.stuck_corners {
+            self.turn_on_corners();
+        }
+    }
+}
+
+impl FromStr for Animation {
+    type Err = ParseError;
+
+    /// Parses a square grid where `#` is a light that is on, and `.` is a light that is off.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const FORMAT: &str = "a row of a square grid of '#' and '.'";
+
+        let lines: Vec<&str> = s.lines().collect();
+        let size = lines.len();
+        let mut lights = Vec::with_capacity(size * size);
+        for (i, line) in lines.iter().enumerate() {
+            let error = || ParseError::new(line, FORMAT).at_line(i + 1);
+            if line.chars().count() != size {
+                return Err(error().with_source(format!("expected {} lights in the row", size)));
+            }
+            for c in line.chars() {
+                match c {
+                    '#' => lights.push(true),
+                    '.' => lights.push(false),
+                    _ => return Err(error().with_source(format!("unexpected character '{}'", c))),
+                }
+            }
+        }
+
+        Ok(Animation {
+            size,
+            lights,
+            stuck_corners: false,
+        })
+    }
+}
+
+/// Returns the number of lights that are on after animating the grid for the provided number of steps.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use advent_of_code::year_2015::day_18::{lights_on_after, Animation};
+///
+/// let animation = Animation::from_str(".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..").unwrap();
+/// assert_eq!(lights_on_after(&animation, 4), 4);
+/// ```
+#[must_use]
+pub fn lights_on_after(animation: &Animation, steps: usize) -> usize {
+    let mut animation = animation.clone();
+    for _ in 0..steps {
+        animation.step();
+    }
+    animation.lights_on()
+}
+
+/// Returns the number of lights that are on after animating the grid for the provided number of steps,
+/// with the four corner lights stuck on.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use advent_of_code::year_2015::day_18::{lights_on_after_stuck, Animation};
+///
+/// let animation = Animation::from_str(".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..").unwrap();
+/// assert_eq!(lights_on_after_stuck(&animation, 5), 17);
+/// ```
+#[must_use]
+pub fn lights_on_after_stuck(animation: &Animation, steps: usize) -> usize {
+    let mut animation = animation.clone();
+    animation.stick_corners();
+    lights_on_after(&animation, steps)
+}
+
+/// Solution to Year 2015 Day 18: Like a GIF For Your Yard.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Like a GIF For Your Yard";
+
+    type Input = Animation;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(Animation::from_str(input.trim())?)
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(lights_on_after(input, 100))
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        Ok(lights_on_after_stuck(input, 100))
+    }
+}
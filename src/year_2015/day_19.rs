@@ -0,0 +1,295 @@
+/*!
+--- Day 19: Medicine for Rudolph ---
+
+Rudolph the Red-Nosed Reindeer is sick!
+His nose isn't shining very brightly, and he needs medicine.
+
+Red-Nosed Reindeer biology isn't similar to regular reindeer biology;
+Rudolph is going to need custom-made medicine.
+Unfortunately, Red-Nosed Reindeer chemistry isn't similar to regular reindeer chemistry, either.
+*/
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use hashbrown::{HashMap, HashSet};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{CapturesWrapper, ParseError};
+
+/// The molecule every medicine is fabricated from.
+const ELECTRON: &str = "e";
+
+/// Represents a replacement of one molecule with another, such as `H => HO`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Replacement {
+    pub from: String,
+    pub to: String,
+}
+
+impl FromStr for Replacement {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static REGEX: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^(?P<from>\w+) => (?P<to>\w+)$").unwrap());
+
+        let caps = CapturesWrapper::captures(&REGEX, s, "{from} => {to}")?;
+        let from = caps.as_string("from");
+        let to = caps.as_string("to");
+
+        Ok(Replacement { from, to })
+    }
+}
+
+/// Represents the replacements a machine can make, and the medicine molecule to calibrate it with.
+#[derive(Clone, Debug)]
+pub struct Machine {
+    pub replacements: Vec<Replacement>,
+    pub medicine: String,
+}
+
+impl Machine {
+    /// Constructs a new `Machine` with the provided replacements and medicine molecule.
+    #[must_use]
+    pub fn new(replacements: Vec<Replacement>, medicine: String) -> Machine {
+        Machine {
+            replacements,
+            medicine,
+        }
+    }
+
+    /// Returns the number of distinct molecules that can be created after one replacement on the medicine molecule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_19::Machine;
+    ///
+    /// let machine = Machine::from_str("H => HO\nH => OH\nO => HH\n\nHOH").unwrap();
+    /// assert_eq!(machine.calibrate(), 4);
+    /// ```
+    #[must_use]
+    pub fn calibrate(&self) -> usize {
+        let mut molecules = HashSet::new();
+        for replacement in &self.replacements {
+            for (i, _) in self.medicine.match_indices(&replacement.from) {
+                let mut molecule =
+                    String::with_capacity(self.medicine.len() + replacement.to.len());
+                molecule.push_str(&self.medicine[..i]);
+                molecule.push_str(&replacement.to);
+                molecule.push_str(&self.medicine[i + replacement.from.len()..]);
+                molecules.insert(molecule);
+            }
+        }
+        molecules.len()
+    }
+
+    /// Returns the fewest number of steps to go from a single electron `e` to the medicine molecule.
+    /// If the steps cannot be counted `None` is returned.
+    ///
+    /// The replacements of the puzzle either turn one element into two, such as `H => HO`,
+    /// or wrap elements in `Rn` and `Ar` separated by `Y`, such as `Ca => SiRnFYFAr`.
+    /// Counting `Rn` and `Ar` as no elements and `Y` as minus one element, every replacement adds exactly one element,
+    /// so every way of fabricating the medicine takes the same number of steps:
+    /// the first step from `e`, plus one step for each element the medicine has over the molecules made from `e`.
+    ///
+    /// The steps cannot be counted if a replacement is not from a single element,
+    /// if a replacement does not add exactly one element,
+    /// if the replacements from `e` make molecules with different numbers of elements,
+    /// or if the medicine cannot be fabricated from `e` at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_19::Machine;
+    ///
+    /// let machine = Machine::from_str("e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOHOHO").unwrap();
+    /// assert_eq!(machine.fewest_steps(), Some(6));
+    /// ```
+    #[must_use]
+    pub fn fewest_steps(&self) -> Option<usize> {
+        if self.medicine == ELECTRON {
+            return Some(0);
+        }
+
+        let mut start = None;
+        for replacement in &self.replacements {
+            if elements(&replacement.from).count() != 1 {
+                return None;
+            }
+
+            let count = count_elements(&replacement.to);
+            if replacement.from == ELECTRON {
+                if *start.get_or_insert(count) != count {
+                    return None;
+                }
+            } else if count != count_elements(&replacement.from) + 1 {
+                return None;
+            }
+        }
+
+        if !self.is_fabricable() {
+            return None;
+        }
+
+        let steps = count_elements(&self.medicine) - start? + 1;
+        usize::try_from(steps).ok()
+    }
+
+    /// Returns `true` if the medicine molecule can be fabricated from `e`.
+    ///
+    /// The replacements are the rules of a grammar over the elements, so the medicine is recognized with an Earley parser
+    /// instead of searching through every way of fabricating it.
+    fn is_fabricable(&self) -> bool {
+        let mut symbols: HashMap<&str, usize> = HashMap::new();
+        let mut symbol = |element| {
+            let next = symbols.len();
+            *symbols.entry(element).or_insert(next)
+        };
+
+        let mut rules = Vec::new();
+        for replacement in self.replacements.iter().filter(|r| !r.to.is_empty()) {
+            let from = symbol(&replacement.from);
+            let to: Vec<usize> = elements(&replacement.to).map(&mut symbol).collect();
+            rules.push((from, to));
+        }
+        let start = symbol(ELECTRON);
+        let tokens: Vec<usize> = elements(&self.medicine).map(&mut symbol).collect();
+
+        // No rule is empty, so a completed item always started at an earlier, finished position.
+        let mut chart: Vec<Vec<Item>> = vec![Vec::new(); tokens.len() + 1];
+        let mut seen: Vec<HashSet<Item>> = vec![HashSet::new(); tokens.len() + 1];
+        let mut add = |chart: &mut Vec<Vec<Item>>, i: usize, item: Item| {
+            if seen[i].insert(item) {
+                chart[i].push(item);
+            }
+        };
+
+        for (rule, _) in rules
+            .iter()
+            .enumerate()
+            .filter(|(_, (from, _))| *from == start)
+        {
+            add(&mut chart, 0, (rule, 0, 0));
+        }
+
+        for i in 0..=tokens.len() {
+            let mut j = 0;
+            while j < chart[i].len() {
+                let (rule, matched, origin) = chart[i][j];
+                j += 1;
+
+                let (from, to) = &rules[rule];
+                match to.get(matched) {
+                    Some(&next) => {
+                        for (predicted, _) in
+                            rules.iter().enumerate().filter(|(_, (f, _))| *f == next)
+                        {
+                            add(&mut chart, i, (predicted, 0, i));
+                        }
+                        if tokens.get(i) == Some(&next) {
+                            add(&mut chart, i + 1, (rule, matched + 1, origin));
+                        }
+                    }
+                    None => {
+                        let completed: Vec<Item> = chart[origin]
+                            .iter()
+                            .filter(|&&(r, m, _)| rules[r].1.get(m) == Some(from))
+                            .map(|&(r, m, o)| (r, m + 1, o))
+                            .collect();
+                        for item in completed {
+                            add(&mut chart, i, item);
+                        }
+                    }
+                }
+            }
+        }
+
+        chart[tokens.len()].iter().any(|&(rule, matched, origin)| {
+            origin == 0 && rules[rule].0 == start && matched == rules[rule].1.len()
+        })
+    }
+}
+
+/// An item of the Earley parser, a rule, the number of its elements matched, and the position the match started at.
+type Item = (usize, usize, usize);
+
+/// Returns an iterator over the elements of the molecule, each an uppercase letter followed by any lowercase letters.
+/// The electron `e` is a single element.
+fn elements(molecule: &str) -> impl Iterator<Item = &str> {
+    let mut starts: Vec<usize> = molecule
+        .char_indices()
+        .filter(|&(i, c)| i == 0 || c.is_ascii_uppercase())
+        .map(|(i, _)| i)
+        .collect();
+    starts.push(molecule.len());
+
+    (0..starts.len() - 1).map(move |i| &molecule[starts[i]..starts[i + 1]])
+}
+
+/// Counts the elements of the molecule, with `Rn` and `Ar` counted as no elements and `Y` as minus one element.
+fn count_elements(molecule: &str) -> isize {
+    elements(molecule)
+        .map(|element| match element {
+            "Rn" | "Ar" => 0,
+            "Y" => -1,
+            _ => 1,
+        })
+        .sum()
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    /// Parses one replacement per line, followed by a blank line and the medicine molecule.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate();
+        let replacements = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(i, line)| Replacement::from_str(line).map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+
+        match lines.next() {
+            Some((_, medicine)) if !medicine.trim().is_empty() => {
+                Ok(Machine::new(replacements, String::from(medicine.trim())))
+            }
+            Some((i, line)) => Err(ParseError::new(line, "{medicine}").at_line(i + 1)),
+            None => Err(ParseError::new("", "{medicine}").at_line(s.lines().count() + 1)),
+        }
+    }
+}
+
+/// Solution to Year 2015 Day 19: Medicine for Rudolph.
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Medicine for Rudolph";
+
+    type Input = Machine;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(Machine::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(input.calibrate())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        input
+            .fewest_steps()
+            .ok_or_else(|| "the medicine molecule cannot be fabricated from 'e'".into())
+    }
+}
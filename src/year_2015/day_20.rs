@@ -0,0 +1,84 @@
+/*!
+--- Day 20: Infinite Elves and Infinite Houses ---
+
+To keep the Elves busy, Santa has them deliver some presents by hand, door-to-door.
+He sends them down a street with infinite houses numbered sequentially: 1, 2, 3, 4, 5, and so on.
+*/
+
+use crate::solution::{Solution, SolutionResult};
+
+/// Returns the lowest house number to get at least the provided number of presents,
+/// when each elf delivers to the provided number of houses with the provided presents per house.
+fn lowest_house(presents: u32, presents_per_house: u32, house_limit: usize) -> usize {
+    // The elf numbered like the last house delivers enough presents to it on their own.
+    let houses = (presents / presents_per_house) as usize + 1;
+    let mut delivered = vec![0u32; houses + 1];
+
+    for elf in 1..=houses {
+        for house in (elf..=houses).step_by(elf).take(house_limit) {
+            delivered[house] += elf as u32 * presents_per_house;
+        }
+    }
+
+    (1..houses)
+        .find(|&house| delivered[house] >= presents)
+        .unwrap_or(houses)
+}
+
+/// Returns the lowest house number to get at least the provided number of presents.
+///
+/// Each elf delivers ten times their number of presents to every house that is a multiple of their number.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_20::lowest_house_number;
+///
+/// assert_eq!(lowest_house_number(70), 4);
+/// assert_eq!(lowest_house_number(130), 8);
+/// ```
+#[must_use]
+pub fn lowest_house_number(presents: u32) -> usize {
+    lowest_house(presents, 10, usize::MAX)
+}
+
+/// Returns the lowest house number to get at least the provided number of presents, with lazy elves.
+///
+/// Each elf delivers eleven times their number of presents to the first fifty houses that are a multiple of their number.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_20::lowest_house_number_lazy_elves;
+///
+/// assert_eq!(lowest_house_number_lazy_elves(70), 4);
+/// ```
+#[must_use]
+pub fn lowest_house_number_lazy_elves(presents: u32) -> usize {
+    lowest_house(presents, 11, 50)
+}
+
+/// Solution to Year 2015 Day 20: Infinite Elves and Infinite Houses.
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Infinite Elves and Infinite Houses";
+
+    type Input = u32;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(input.trim().parse()?)
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(lowest_house_number(*input))
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        Ok(lowest_house_number_lazy_elves(*input))
+    }
+}
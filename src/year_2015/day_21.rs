@@ -0,0 +1,229 @@
+/*!
+--- Day 21: RPG Simulator 20XX ---
+
+Little Henry Case got a new video game for Christmas.
+It's an RPG, and he's stuck on a boss.
+He needs to know what equipment to buy at the shop.
+*/
+
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{CapturesWrapper, ParseError};
+
+/// Represents an item for sale in the shop.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Item {
+    pub name: &'static str,
+    pub cost: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+const fn item(name: &'static str, cost: u32, damage: u32, armor: u32) -> Item {
+    Item {
+        name,
+        cost,
+        damage,
+        armor,
+    }
+}
+
+/// The weapons for sale in the shop, exactly one must be bought.
+pub const WEAPONS: [Item; 5] = [
+    item("Dagger", 8, 4, 0),
+    item("Shortsword", 10, 5, 0),
+    item("Warhammer", 25, 6, 0),
+    item("Longsword", 40, 7, 0),
+    item("Greataxe", 74, 8, 0),
+];
+
+/// The armor for sale in the shop, at most one can be bought.
+pub const ARMOR: [Item; 5] = [
+    item("Leather", 13, 0, 1),
+    item("Chainmail", 31, 0, 2),
+    item("Splintmail", 53, 0, 3),
+    item("Bandedmail", 75, 0, 4),
+    item("Platemail", 102, 0, 5),
+];
+
+/// The rings for sale in the shop, at most two different rings can be bought.
+pub const RINGS: [Item; 6] = [
+    item("Damage +1", 25, 1, 0),
+    item("Damage +2", 50, 2, 0),
+    item("Damage +3", 100, 3, 0),
+    item("Defense +1", 20, 0, 1),
+    item("Defense +2", 40, 0, 2),
+    item("Defense +3", 80, 0, 3),
+];
+
+/// The hit points the player starts with.
+const PLAYER_HIT_POINTS: u32 = 100;
+
+/// Represents the stats of a character in the game.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Character {
+    pub hit_points: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+impl Character {
+    /// Constructs a new `Character` with the provided hit points wearing the provided items.
+    #[must_use]
+    pub fn equipped(hit_points: u32, items: &[Item]) -> Character {
+        Character {
+            hit_points,
+            damage: items.iter().map(|item| item.damage).sum(),
+            armor: items.iter().map(|item| item.armor).sum(),
+        }
+    }
+
+    /// Returns the number of turns this character needs to defeat the opponent.
+    fn turns_to_defeat(&self, opponent: &Character) -> u32 {
+        let damage = self.damage.saturating_sub(opponent.armor).max(1);
+        opponent.hit_points.div_ceil(damage)
+    }
+
+    /// Returns `true` if this character wins a fight against the opponent, attacking first; `false` otherwise.
+    ///
+    /// Each attack deals damage equal to the attacker's damage minus the defender's armor, but always at least 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::year_2015::day_21::Character;
+    ///
+    /// let player = Character { hit_points: 8, damage: 5, armor: 5 };
+    /// let boss = Character { hit_points: 12, damage: 7, armor: 2 };
+    /// assert!(player.defeats(&boss));
+    /// ```
+    #[must_use]
+    pub fn defeats(&self, opponent: &Character) -> bool {
+        self.turns_to_defeat(opponent) <= opponent.turns_to_defeat(self)
+    }
+}
+
+impl FromStr for Character {
+    type Err = ParseError;
+
+    /// Parses the stats of a character, one per line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^Hit Points: (?P<hit_points>\d+)\s+Damage: (?P<damage>\d+)\s+Armor: (?P<armor>\d+)$").unwrap()
+        });
+
+        let caps = CapturesWrapper::captures(
+            &REGEX,
+            s.trim(),
+            "Hit Points: {hit_points}\\nDamage: {damage}\\nArmor: {armor}",
+        )?;
+        let hit_points = caps.parse("hit_points")?;
+        let damage = caps.parse("damage")?;
+        let armor = caps.parse("armor")?;
+
+        Ok(Character {
+            hit_points,
+            damage,
+            armor,
+        })
+    }
+}
+
+/// Returns every combination of items the shop allows, as the total cost and the equipped player.
+fn loadouts() -> Vec<(u32, Character)> {
+    let armor: Vec<Option<Item>> = std::iter::once(None)
+        .chain(ARMOR.iter().copied().map(Some))
+        .collect();
+
+    let mut rings: Vec<Vec<Item>> = vec![Vec::new()];
+    for (i, &first) in RINGS.iter().enumerate() {
+        rings.push(vec![first]);
+        for &second in &RINGS[i + 1..] {
+            rings.push(vec![first, second]);
+        }
+    }
+
+    let mut loadouts = Vec::new();
+    for &weapon in &WEAPONS {
+        for &armor in &armor {
+            for rings in &rings {
+                let mut items = vec![weapon];
+                items.extend(armor);
+                items.extend(rings);
+
+                let cost = items.iter().map(|item| item.cost).sum();
+                loadouts.push((cost, Character::equipped(PLAYER_HIT_POINTS, &items)));
+            }
+        }
+    }
+    loadouts
+}
+
+/// Returns the least amount of gold the player can spend and still win the fight against the boss.
+/// If no combination of items wins `None` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_21::{least_gold_to_win, Character};
+///
+/// let boss = Character { hit_points: 100, damage: 4, armor: 0 };
+/// assert_eq!(least_gold_to_win(&boss), Some(8));
+/// ```
+#[must_use]
+pub fn least_gold_to_win(boss: &Character) -> Option<u32> {
+    loadouts()
+        .into_iter()
+        .filter(|(_, player)| player.defeats(boss))
+        .map(|(cost, _)| cost)
+        .min()
+}
+
+/// Returns the most amount of gold the player can spend and still lose the fight against the boss.
+/// If no combination of items loses `None` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_21::{most_gold_to_lose, Character};
+///
+/// let boss = Character { hit_points: 1, damage: 0, armor: 0 };
+/// assert_eq!(most_gold_to_lose(&boss), None);
+/// ```
+#[must_use]
+pub fn most_gold_to_lose(boss: &Character) -> Option<u32> {
+    loadouts()
+        .into_iter()
+        .filter(|(_, player)| !player.defeats(boss))
+        .map(|(cost, _)| cost)
+        .max()
+}
+
+/// Solution to Year 2015 Day 21: RPG Simulator 20XX.
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "RPG Simulator 20XX";
+
+    type Input = Character;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(Character::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        least_gold_to_win(input).ok_or_else(|| "no combination of items defeats the boss".into())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        most_gold_to_lose(input).ok_or_else(|| "every combination of items defeats the boss".into())
+    }
+}
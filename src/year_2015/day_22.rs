@@ -0,0 +1,267 @@
+/*!
+--- Day 22: Wizard Simulator 20XX ---
+
+Little Henry Case decides that defeating bosses with swords and stuff is boring.
+Now he's playing the game with a wizard.
+Of course, he gets stuck on another boss and needs your help again.
+*/
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::str::FromStr;
+
+use hashbrown::HashSet;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{CapturesWrapper, ParseError};
+
+/// Represents the spells the wizard can cast.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Spell {
+    /// Instantly does 4 damage.
+    MagicMissile,
+    /// Instantly does 2 damage and heals 2 hit points.
+    Drain,
+    /// Starts an effect that increases armor by 7 for 6 turns.
+    Shield,
+    /// Starts an effect that deals 3 damage at the start of each turn for 6 turns.
+    Poison,
+    /// Starts an effect that gives 101 new mana at the start of each turn for 5 turns.
+    Recharge,
+}
+
+impl Spell {
+    /// Every spell the wizard can cast.
+    pub const ALL: [Spell; 5] = [
+        Spell::MagicMissile,
+        Spell::Drain,
+        Spell::Shield,
+        Spell::Poison,
+        Spell::Recharge,
+    ];
+
+    /// Returns the mana it costs to cast the spell.
+    #[must_use]
+    pub fn cost(self) -> u32 {
+        match self {
+            Spell::MagicMissile => 53,
+            Spell::Drain => 73,
+            Spell::Shield => 113,
+            Spell::Poison => 173,
+            Spell::Recharge => 229,
+        }
+    }
+}
+
+/// Represents the difficulty of the fight.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Difficulty {
+    Normal,
+    /// The wizard loses 1 hit point at the start of each of their turns.
+    Hard,
+}
+
+/// Represents the stats of the wizard at the start of a fight.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Wizard {
+    pub hit_points: u32,
+    pub mana: u32,
+}
+
+impl Default for Wizard {
+    /// The wizard starts with 50 hit points and 500 mana.
+    fn default() -> Self {
+        Wizard {
+            hit_points: 50,
+            mana: 500,
+        }
+    }
+}
+
+/// Represents the stats of the boss.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Boss {
+    pub hit_points: u32,
+    pub damage: u32,
+}
+
+impl FromStr for Boss {
+    type Err = ParseError;
+
+    /// Parses the stats of the boss, one per line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^Hit Points: (?P<hit_points>\d+)\s+Damage: (?P<damage>\d+)$").unwrap()
+        });
+
+        let caps = CapturesWrapper::captures(
+            &REGEX,
+            s.trim(),
+            "Hit Points: {hit_points}\\nDamage: {damage}",
+        )?;
+        let hit_points = caps.parse("hit_points")?;
+        let damage = caps.parse("damage")?;
+
+        Ok(Boss { hit_points, damage })
+    }
+}
+
+/// Represents the state of a fight at the start of the wizard's turn.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Fight {
+    wizard_hit_points: i32,
+    mana: u32,
+    boss_hit_points: i32,
+    shield: u8,
+    poison: u8,
+    recharge: u8,
+}
+
+impl Fight {
+    /// Applies the active effects at the start of a turn, and returns the wizard's armor for the turn.
+    fn apply_effects(&mut self) -> u32 {
+        let armor = if self.shield > 0 { 7 } else { 0 };
+        self.shield = self.shield.saturating_sub(1);
+
+        if self.poison > 0 {
+            self.boss_hit_points -= 3;
+            self.poison -= 1;
+        }
+
+        if self.recharge > 0 {
+            self.mana += 101;
+            self.recharge -= 1;
+        }
+        armor
+    }
+
+    /// Casts the spell, or returns `false` if the wizard cannot afford it or its effect is still active.
+    fn cast(&mut self, spell: Spell) -> bool {
+        if self.mana < spell.cost() {
+            return false;
+        }
+
+        match spell {
+            Spell::MagicMissile => self.boss_hit_points -= 4,
+            Spell::Drain => {
+                self.boss_hit_points -= 2;
+                self.wizard_hit_points += 2;
+            }
+            Spell::Shield if self.shield == 0 => self.shield = 6,
+            Spell::Poison if self.poison == 0 => self.poison = 6,
+            Spell::Recharge if self.recharge == 0 => self.recharge = 5,
+            _ => return false,
+        }
+        self.mana -= spell.cost();
+        true
+    }
+}
+
+/// Represents a fight in the queue, either at the start of the wizard's turn or already won.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Node {
+    Won,
+    Fighting(Fight),
+}
+
+/// Returns the least amount of mana the wizard can spend and still win the fight against the boss.
+/// If the wizard cannot win `None` is returned.
+///
+/// The wizard always takes the first turn, and loses if they cannot afford any spell.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_22::{least_mana_to_win, Boss, Difficulty, Wizard};
+///
+/// let wizard = Wizard { hit_points: 10, mana: 250 };
+/// let boss = Boss { hit_points: 13, damage: 8 };
+/// assert_eq!(least_mana_to_win(&wizard, &boss, Difficulty::Normal), Some(226));
+/// ```
+#[must_use]
+pub fn least_mana_to_win(wizard: &Wizard, boss: &Boss, difficulty: Difficulty) -> Option<u32> {
+    let start = Fight {
+        wizard_hit_points: wizard.hit_points as i32,
+        mana: wizard.mana,
+        boss_hit_points: boss.hit_points as i32,
+        shield: 0,
+        poison: 0,
+        recharge: 0,
+    };
+
+    let mut queue = BinaryHeap::new();
+    let mut visited = HashSet::new();
+    queue.push(Reverse((0, Node::Fighting(start))));
+
+    while let Some(Reverse((spent, node))) = queue.pop() {
+        let mut fight = match node {
+            Node::Won => return Some(spent),
+            Node::Fighting(fight) => fight,
+        };
+        if !visited.insert(fight) {
+            continue;
+        }
+
+        if difficulty == Difficulty::Hard {
+            fight.wizard_hit_points -= 1;
+            if fight.wizard_hit_points <= 0 {
+                continue;
+            }
+        }
+
+        fight.apply_effects();
+        if fight.boss_hit_points <= 0 {
+            queue.push(Reverse((spent, Node::Won)));
+            continue;
+        }
+
+        for &spell in &Spell::ALL {
+            let mut next = fight;
+            if !next.cast(spell) {
+                continue;
+            }
+            let spent = spent + spell.cost();
+
+            let armor = next.apply_effects();
+            if next.boss_hit_points <= 0 {
+                queue.push(Reverse((spent, Node::Won)));
+                continue;
+            }
+
+            next.wizard_hit_points -= boss.damage.saturating_sub(armor).max(1) as i32;
+            if next.wizard_hit_points > 0 {
+                queue.push(Reverse((spent, Node::Fighting(next))));
+            }
+        }
+    }
+    None
+}
+
+/// Solution to Year 2015 Day 22: Wizard Simulator 20XX.
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Wizard Simulator 20XX";
+
+    type Input = Boss;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(Boss::from_str(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        least_mana_to_win(&Wizard::default(), input, Difficulty::Normal)
+            .ok_or_else(|| "the wizard cannot defeat the boss".into())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        least_mana_to_win(&Wizard::default(), input, Difficulty::Hard)
+            .ok_or_else(|| "the wizard cannot defeat the boss".into())
+    }
+}
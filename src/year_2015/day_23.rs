@@ -0,0 +1,202 @@
+/*!
+--- Day 23: Opening the Turing Lock ---
+
+Little Jane Marie just got her very first computer for Christmas from some unknown benefactor.
+It comes with instructions and an example program, but the computer itself seems to be malfunctioning.
+She's curious what the program does, and would like you to help her run it.
+*/
+
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, CapturesWrapper, ParseError};
+
+/// Represents the two registers of the computer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            _ => Err(ParseError::new(s, "{a|b}")),
+        }
+    }
+}
+
+/// Represents the instructions the computer can run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    /// Sets the register to half its current value.
+    Half(Register),
+    /// Sets the register to triple its current value.
+    Triple(Register),
+    /// Increments the register by one.
+    Increment(Register),
+    /// Jumps by the offset relative to itself.
+    Jump(isize),
+    /// Jumps by the offset if the register is even.
+    JumpIfEven(Register, isize),
+    /// Jumps by the offset if the register is one.
+    JumpIfOne(Register, isize),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^(?P<opcode>hlf|tpl|inc|jmp|jie|jio) (?:(?P<register>[ab]),? ?)?(?P<offset>[+-]\d+)?$").unwrap()
+        });
+        const FORMAT: &str =
+            "{hlf|tpl|inc} {register}, jmp {offset}, or {jie|jio} {register}, {offset}";
+
+        let caps = CapturesWrapper::captures(&REGEX, s, FORMAT)?;
+        let register = match caps.name("register") {
+            Some(_) => Some(caps.parse("register")?),
+            None => None,
+        };
+        let offset = match caps.name("offset") {
+            Some(_) => Some(caps.parse("offset")?),
+            None => None,
+        };
+
+        match (caps.as_str("opcode"), register, offset) {
+            ("hlf", Some(register), None) => Ok(Instruction::Half(register)),
+            ("tpl", Some(register), None) => Ok(Instruction::Triple(register)),
+            ("inc", Some(register), None) => Ok(Instruction::Increment(register)),
+            ("jmp", None, Some(offset)) => Ok(Instruction::Jump(offset)),
+            ("jie", Some(register), Some(offset)) => Ok(Instruction::JumpIfEven(register, offset)),
+            ("jio", Some(register), Some(offset)) => Ok(Instruction::JumpIfOne(register, offset)),
+            _ => Err(ParseError::new(s, FORMAT)),
+        }
+    }
+}
+
+/// Represents the computer with two registers, each able to hold any non-negative integer.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Computer {
+    a: u64,
+    b: u64,
+}
+
+impl Computer {
+    /// Constructs a new `Computer` with the provided starting values in the registers.
+    #[must_use]
+    pub fn new(a: u64, b: u64) -> Computer {
+        Computer { a, b }
+    }
+
+    /// Returns the value in the provided register.
+    #[must_use]
+    pub fn register(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+        }
+    }
+
+    fn register_mut(&mut self, register: Register) -> &mut u64 {
+        match register {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+        }
+    }
+
+    /// Runs the program until it tries to run an instruction beyond the ones defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use advent_of_code::year_2015::day_23::{Computer, Instruction, Register};
+    ///
+    /// let program: Vec<Instruction> = ["inc a", "jio a, +2", "tpl a", "inc a"]
+    ///     .iter()
+    ///     .map(|s| Instruction::from_str(s).unwrap())
+    ///     .collect();
+    ///
+    /// let mut computer = Computer::default();
+    /// computer.run(&program);
+    /// assert_eq!(computer.register(Register::A), 2);
+    /// ```
+    pub fn run(&mut self, program: &[Instruction]) {
+        let mut pointer: isize = 0;
+        while pointer >= 0 && (pointer as usize) < program.len() {
+            let offset = match program[pointer as usize] {
+                Instruction::Half(register) => {
+                    *self.register_mut(register) /= 2;
+                    1
+                }
+                Instruction::Triple(register) => {
+                    *self.register_mut(register) *= 3;
+                    1
+                }
+                Instruction::Increment(register) => {
+                    *self.register_mut(register) += 1;
+                    1
+                }
+                Instruction::Jump(offset) => offset,
+                Instruction::JumpIfEven(register, offset) => {
+                    if self.register(register).is_multiple_of(2) {
+                        offset
+                    } else {
+                        1
+                    }
+                }
+                Instruction::JumpIfOne(register, offset) => {
+                    if self.register(register) == 1 {
+                        offset
+                    } else {
+                        1
+                    }
+                }
+            };
+            pointer += offset;
+        }
+    }
+}
+
+/// Solution to Year 2015 Day 23: Opening the Turing Lock.
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Opening the Turing Lock";
+
+    type Input = Vec<Instruction>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((parsed.items, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        let mut computer = Computer::new(0, 0);
+        computer.run(input);
+        Ok(computer.register(Register::B))
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        let mut computer = Computer::new(1, 0);
+        computer.run(input);
+        Ok(computer.register(Register::B))
+    }
+}
@@ -0,0 +1,110 @@
+/*!
+--- Day 24: It Hangs in the Balance ---
+
+It's Christmas Eve, and Santa is loading up the sleigh for this year's deliveries.
+However, there's one small problem: he can't get the sleigh to balance.
+If it isn't balanced, he can't defy physics, and nobody gets presents this year.
+*/
+
+use itertools::Itertools;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{parse_lines, parse_lines_lenient, ParseError};
+
+/// Returns `true` if the packages can be split into the provided number of groups with the same weight; `false` otherwise.
+fn can_split(packages: &[u64], groups: usize, weight: u64) -> bool {
+    if groups == 1 {
+        return packages.iter().sum::<u64>() == weight;
+    }
+
+    (1..=packages.len()).any(|size| {
+        packages
+            .iter()
+            .copied()
+            .combinations(size)
+            .filter(|group| group.iter().sum::<u64>() == weight)
+            .any(|group| {
+                let rest: Vec<u64> = packages
+                    .iter()
+                    .copied()
+                    .filter(|package| !group.contains(package))
+                    .collect();
+                can_split(&rest, groups - 1, weight)
+            })
+    })
+}
+
+/// Returns the quantum entanglement of the ideal first group of packages, when split into the provided number of groups.
+/// If the packages cannot be split into groups with the same weight `None` is returned.
+///
+/// The ideal first group has the fewest packages, and then the smallest quantum entanglement,
+/// which is the product of the weights of its packages.
+/// Every package is expected to have a different weight.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_24::ideal_entanglement;
+///
+/// let packages = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+/// assert_eq!(ideal_entanglement(&packages, 3), Some(99));
+/// assert_eq!(ideal_entanglement(&packages, 4), Some(44));
+/// ```
+#[must_use]
+pub fn ideal_entanglement(packages: &[u64], groups: usize) -> Option<u64> {
+    let total: u64 = packages.iter().sum();
+    if groups == 0 || !total.is_multiple_of(groups as u64) {
+        return None;
+    }
+    let weight = total / groups as u64;
+
+    (1..=packages.len()).find_map(|size| {
+        packages
+            .iter()
+            .copied()
+            .combinations(size)
+            .filter(|group| group.iter().sum::<u64>() == weight)
+            .sorted_by_key(|group| group.iter().product::<u64>())
+            .find(|group| {
+                let rest: Vec<u64> = packages
+                    .iter()
+                    .copied()
+                    .filter(|package| !group.contains(package))
+                    .collect();
+                can_split(&rest, groups - 1, weight)
+            })
+            .map(|group| group.iter().product())
+    })
+}
+
+/// Solution to Year 2015 Day 24: It Hangs in the Balance.
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "It Hangs in the Balance";
+
+    type Input = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn parse_lenient(input: &str) -> SolutionResult<(Self::Input, Vec<ParseError>)> {
+        let parsed = parse_lines_lenient(input);
+        Ok((parsed.items, parsed.rejected))
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        ideal_entanglement(input, 3)
+            .ok_or_else(|| "the packages cannot be split into three equal groups".into())
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        ideal_entanglement(input, 4)
+            .ok_or_else(|| "the packages cannot be split into four equal groups".into())
+    }
+}
@@ -0,0 +1,114 @@
+/*!
+--- Day 25: Let It Snow ---
+
+Merry Christmas!
+Santa is booting up his weather machine; looks like you might get a white Christmas after all.
+
+The weather machine beeps!
+On the console of the machine is a copy protection message asking you to enter a code from the instruction manual.
+*/
+
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::{CapturesWrapper, ParseError};
+
+const FIRST_CODE: u64 = 20_151_125;
+const MULTIPLIER: u64 = 252_533;
+const MODULUS: u64 = 33_554_393;
+
+/// Represents the position of a code in the manual, where the top-left code is at row 1, column 1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Position {
+    pub row: u64,
+    pub column: u64,
+}
+
+impl FromStr for Position {
+    type Err = ParseError;
+
+    /// Parses the position from a message containing `row {row}, column {column}`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static REGEX: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"row (?P<row>\d+), column (?P<column>\d+)").unwrap());
+
+        let caps = CapturesWrapper::captures(&REGEX, s, "... row {row}, column {column} ...")?;
+        let row = caps.parse("row")?;
+        let column = caps.parse("column")?;
+
+        if row == 0 || column == 0 {
+            return Err(caps.error("rows and columns start at 1"));
+        }
+        Ok(Position { row, column })
+    }
+}
+
+/// Returns the code at the provided position in the manual.
+///
+/// Codes are filled in diagonally from the bottom-left to the top-right,
+/// and each code is the previous code multiplied by 252533, modulo 33554393.
+///
+/// # Panics
+///
+/// Will panic if the row or column is zero.
+///
+/// # Examples
+///
+/// ```
+/// use advent_of_code::year_2015::day_25::{code_at, Position};
+///
+/// assert_eq!(code_at(Position { row: 1, column: 1 }), 20151125);
+/// assert_eq!(code_at(Position { row: 4, column: 2 }), 32451966);
+/// assert_eq!(code_at(Position { row: 6, column: 6 }), 27995004);
+/// ```
+#[must_use]
+pub fn code_at(position: Position) -> u64 {
+    assert!(
+        position.row != 0 && position.column != 0,
+        "rows and columns start at 1"
+    );
+
+    let diagonal = position.row + position.column - 1;
+    let index = diagonal * (diagonal - 1) / 2 + position.column - 1;
+
+    let mut code = FIRST_CODE;
+    let mut base = MULTIPLIER;
+    let mut exponent = index;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            code = code * base % MODULUS;
+        }
+        base = base * base % MODULUS;
+        exponent >>= 1;
+    }
+    code
+}
+
+/// Solution to Year 2015 Day 25: Let It Snow.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Let It Snow";
+
+    type Input = Position;
+    type PartOne = u64;
+    type PartTwo = &'static str;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        Ok(Position::from_str(input.trim())?)
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(code_at(*input))
+    }
+
+    /// The last star is awarded for completing every other puzzle of the year.
+    fn part_two(_input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        Ok("Merry Christmas!")
+    }
+}
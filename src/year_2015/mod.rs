@@ -1,3 +1,5 @@
+use crate::solution::Puzzle;
+
 pub mod day_01;
 pub mod day_02;
 pub mod day_03;
@@ -12,3 +14,43 @@ pub mod day_11;
 pub mod day_12;
 pub mod day_13;
 pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+
+/// The puzzles for 2015, ordered by day.
+pub static PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day_01::Day01>(),
+    Puzzle::new::<day_02::Day02>(),
+    Puzzle::new::<day_03::Day03>(),
+    Puzzle::new::<day_04::Day04>(),
+    Puzzle::new::<day_05::Day05>(),
+    Puzzle::new::<day_06::Day06>(),
+    Puzzle::new::<day_07::Day07>(),
+    Puzzle::new::<day_08::Day08>(),
+    Puzzle::new::<day_09::Day09>(),
+    Puzzle::new::<day_10::Day10>(),
+    Puzzle::new::<day_11::Day11>(),
+    Puzzle::new::<day_12::Day12>(),
+    Puzzle::new::<day_13::Day13>(),
+    Puzzle::new::<day_14::Day14>(),
+    Puzzle::new::<day_15::Day15>(),
+    Puzzle::new::<day_16::Day16>(),
+    Puzzle::new::<day_17::Day17>(),
+    Puzzle::new::<day_18::Day18>(),
+    Puzzle::new::<day_19::Day19>(),
+    Puzzle::new::<day_20::Day20>(),
+    Puzzle::new::<day_21::Day21>(),
+    Puzzle::new::<day_22::Day22>(),
+    Puzzle::new::<day_23::Day23>(),
+    Puzzle::new::<day_24::Day24>(),
+    Puzzle::new::<day_25::Day25>(),
+];
@@ -0,0 +1,158 @@
+/*!
+--- Day 1: No Time for a Taxicab ---
+
+Santa's sleigh uses a very high-precision clock to guide its movements, and the clock's oscillator is regulated by stars.
+Unfortunately, the stars have been stolen... by the Easter Bunny.
+
+You're airdropped near Easter Bunny Headquarters in a city somewhere.
+"Near", unfortunately, is as close as you can get - the instructions on the Easter Bunny Recruiting Document the Elves intercepted start here, and nobody had time to work them out further.
+*/
+
+use std::str::FromStr;
+
+use hashbrown::HashSet;
+
+use crate::solution::{Solution, SolutionResult};
+use crate::util::ParseError;
+
+/// Represents a direction to turn before walking.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// Represents an instruction to turn and then walk forward the provided number of blocks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Step {
+    pub turn: Turn,
+    pub blocks: i32,
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const FORMAT: &str = "{L|R}{blocks}";
+
+        let turn = match s.as_bytes().first() {
+            Some(b'L') => Turn::Left,
+            Some(b'R') => Turn::Right,
+            _ => return Err(ParseError::new(s, FORMAT)),
+        };
+        let blocks = s[1..]
+            .parse()
+            .map_err(|err| ParseError::new(s, FORMAT).with_source(err))?;
+        if blocks < 0 {
+            return Err(ParseError::new(s, FORMAT).with_source("blocks cannot be negative"));
+        }
+
+        Ok(Step { turn, blocks })
+    }
+}
+
+/// Walks the provided steps from the starting point facing north,
+/// calling the provided function with every block visited along the way.
+fn walk<F>(steps: &[Step], mut visit: F)
+where
+    F: FnMut((i32, i32)) -> bool,
+{
+    let (mut x, mut y) = (0, 0);
+    let (mut dx, mut dy) = (0, 1);
+
+    for step in steps {
+        (dx, dy) = match step.turn {
+            Turn::Left => (-dy, dx),
+            Turn::Right => (dy, -dx),
+        };
+        for _ in 0..step.blocks {
+            x += dx;
+            y += dy;
+            if !visit((x, y)) {
+                return;
+            }
+        }
+    }
+}
+
+/// Returns the number of blocks away from the starting point after following every step.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use advent_of_code::year_2016::day_01::{distance_to_destination, Step};
+///
+/// let steps: Vec<Step> = "R5, L5, R5, R3".split(", ").map(|s| Step::from_str(s).unwrap()).collect();
+/// assert_eq!(distance_to_destination(&steps), 12);
+/// ```
+#[must_use]
+pub fn distance_to_destination(steps: &[Step]) -> i32 {
+    let mut destination = (0, 0);
+    walk(steps, |block| {
+        destination = block;
+        true
+    });
+    destination.0.abs() + destination.1.abs()
+}
+
+/// Returns the number of blocks away from the starting point of the first block visited twice.
+/// If no block is visited twice `None` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use advent_of_code::year_2016::day_01::{distance_to_first_revisit, Step};
+///
+/// let steps: Vec<Step> = "R8, R4, R4, R8".split(", ").map(|s| Step::from_str(s).unwrap()).collect();
+/// assert_eq!(distance_to_first_revisit(&steps), Some(4));
+/// ```
+#[must_use]
+pub fn distance_to_first_revisit(steps: &[Step]) -> Option<i32> {
+    let mut visited = HashSet::new();
+    visited.insert((0, 0));
+
+    let mut revisited = None;
+    walk(steps, |block| {
+        if visited.insert(block) {
+            true
+        } else {
+            revisited = Some(block);
+            false
+        }
+    });
+    revisited.map(|(x, y): (i32, i32)| x.abs() + y.abs())
+}
+
+/// Solution to Year 2016 Day 1: No Time for a Taxicab.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "No Time for a Taxicab";
+
+    type Input = Vec<Step>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Input> {
+        let steps = input
+            .trim()
+            .split(", ")
+            .map(Step::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(steps)
+    }
+
+    fn part_one(input: &Self::Input) -> SolutionResult<Self::PartOne> {
+        Ok(distance_to_destination(input))
+    }
+
+    fn part_two(input: &Self::Input) -> SolutionResult<Self::PartTwo> {
+        distance_to_first_revisit(input).ok_or_else(|| "no block is visited twice".into())
+    }
+}
@@ -0,0 +1,6 @@
+use crate::solution::Puzzle;
+
+pub mod day_01;
+
+/// The puzzles for 2016, ordered by day.
+pub static PUZZLES: &[Puzzle] = &[Puzzle::new::<day_01::Day01>()];
@@ -0,0 +1,96 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use advent_of_code::answers::{AnswerKey, Status};
+use advent_of_code::report::Record;
+use advent_of_code::solution::Part;
+
+#[test]
+fn test_answer_key() {
+    let key = AnswerKey::from_str(
+        "# Comment\n\n[day-01]\npart-one = 280\npart-two = \"1797\"\n\n[day-11]\npart-one = \"cqjxxyzz\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(key.get(1, Part::One), Some("280"));
+    assert_eq!(key.get(1, Part::Two), Some("1797"));
+    assert_eq!(key.get(11, Part::One), Some("cqjxxyzz"));
+    assert_eq!(key.get(11, Part::Two), None);
+    assert_eq!(key.get(2, Part::One), None);
+}
+
+#[test]
+fn test_answer_key_empty() {
+    let key = AnswerKey::from_str("").unwrap();
+    assert_eq!(key, AnswerKey::new());
+    assert_eq!(key.check(1, Part::One, "280"), Status::Unknown);
+}
+
+#[test]
+fn test_answer_key_bad_input() {
+    assert!(AnswerKey::from_str("part-one = 280").is_err());
+    assert!(AnswerKey::from_str("[day-01").is_err());
+    assert!(AnswerKey::from_str("[pancakes]").is_err());
+    assert!(AnswerKey::from_str("[day-01]\npart-three = 280").is_err());
+    assert!(AnswerKey::from_str("[day-01]\npart-one = pancakes").is_err());
+    assert!(AnswerKey::from_str("[day-01]\npart-one = \"280").is_err());
+    assert!(AnswerKey::from_str("[day-01]\npart-one").is_err());
+}
+
+#[test]
+fn test_answer_key_error_line_number() {
+    let err = AnswerKey::from_str("[day-01]\npart-one = 280\npart-two 1797").unwrap_err();
+    assert!(err.to_string().starts_with("line 3:"));
+}
+
+#[test]
+fn test_answer_key_path() {
+    let path = AnswerKey::path(Path::new("input"), 2015);
+    assert_eq!(path, PathBuf::from("input/2015.toml"));
+}
+
+#[test]
+fn test_record_check() {
+    let key = AnswerKey::from_str("[day-01]\npart-one = 280\npart-two = 1").unwrap();
+    let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+    let contents = fs::read_to_string("input/2015/day-01.txt").expect("Failed to read file.");
+    let solved = puzzle.solve_parts(&contents, &Part::ALL).unwrap();
+
+    let mut records = Record::answers(puzzle, &solved);
+    records.iter_mut().for_each(|record| record.check(&key));
+
+    assert_eq!(records[0].status, Some(Status::Pass));
+    assert_eq!(records[0].expected, None);
+    assert!(!records[0].is_error());
+
+    assert_eq!(records[1].status, Some(Status::Fail));
+    assert_eq!(records[1].expected, Some(String::from("1")));
+    assert!(records[1].is_error());
+}
+
+fn check_answers_file(year: u16) {
+    let path = AnswerKey::path(Path::new("input"), year);
+    let contents = fs::read_to_string(path).expect("Failed to read file.");
+    let key = AnswerKey::from_str(&contents).unwrap();
+
+    // Every puzzle with a checked in input must have known answers.
+    let puzzles = advent_of_code::puzzles()
+        .filter(|puzzle| puzzle.year == year)
+        .filter(|puzzle| puzzle.input_path(Path::new("input")).exists());
+    for puzzle in puzzles {
+        for &part in &Part::ALL {
+            assert!(key.get(puzzle.day, part).is_some());
+        }
+    }
+}
+
+#[test]
+fn test_answers_file_2015() {
+    check_answers_file(2015);
+}
+
+#[test]
+fn test_answers_file_2016() {
+    check_answers_file(2016);
+}
@@ -0,0 +1,202 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use advent_of_code::cli::{InputSource, Options, Selection};
+use advent_of_code::report::Format;
+use advent_of_code::solution::Part;
+
+#[test]
+fn test_options_default() {
+    let options = Options::parse(Vec::<String>::new()).unwrap();
+    assert_eq!(options.years, Selection::all());
+    assert_eq!(options.days, Selection::all());
+    assert_eq!(options.selected_parts(), vec![Part::One, Part::Two]);
+    assert!(!options.help);
+}
+
+#[test]
+fn test_options_year_day_part() {
+    let options = Options::parse(vec!["--year", "2015", "--day", "6", "--part", "2"]).unwrap();
+    assert!(options.years.contains(2015));
+    assert!(!options.years.contains(2016));
+    assert!(options.days.contains(6));
+    assert!(!options.days.contains(7));
+    assert_eq!(options.selected_parts(), vec![Part::Two]);
+}
+
+#[test]
+fn test_options_short_and_inline() {
+    let options = Options::parse(vec!["-y=2015", "-d", "1..=10", "--part=1"]).unwrap();
+    assert!(options.years.contains(2015));
+    assert!(options.days.contains(10));
+    assert_eq!(options.selected_parts(), vec![Part::One]);
+}
+
+#[test]
+fn test_options_selects_puzzle() {
+    let options = Options::parse(vec!["--day", "2..4"]).unwrap();
+    let selected: Vec<u8> = advent_of_code::puzzles()
+        .filter(|puzzle| options.selects(puzzle))
+        .map(|puzzle| puzzle.day)
+        .collect();
+    assert_eq!(selected, vec![2, 3]);
+}
+
+#[test]
+fn test_options_input() {
+    let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+
+    let options = Options::parse(vec!["--input", "my-input.txt"]).unwrap();
+    let source = options.input_source(puzzle);
+    assert_eq!(source, InputSource::File(PathBuf::from("my-input.txt")));
+
+    let options = Options::parse(vec!["-i", "-"]).unwrap();
+    assert_eq!(options.input_source(puzzle), InputSource::Stdin);
+}
+
+#[test]
+fn test_options_key() {
+    let puzzle = advent_of_code::puzzle(2015, 11).unwrap();
+
+    let options = Options::parse(vec!["--day", "11", "--key", "abcdefgh"]).unwrap();
+    let source = options.input_source(puzzle);
+    assert_eq!(source, InputSource::Text(String::from("abcdefgh")));
+
+    let contents = source.read().unwrap();
+    let answers = puzzle.solve(&contents).unwrap();
+    assert_eq!(answers.part_one, "abcdffaa");
+    assert_eq!(answers.part_two, "abcdffbb");
+}
+
+#[test]
+fn test_options_input_dir() {
+    let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+
+    let options = Options::parse(Vec::<String>::new()).unwrap();
+    let source = options.input_source(puzzle);
+    assert_eq!(
+        source,
+        InputSource::File(PathBuf::from("input/2015/day-01.txt"))
+    );
+
+    let options = Options::parse(vec!["--input-dir=/tmp/inputs"]).unwrap();
+    let source = options.input_source(puzzle);
+    assert_eq!(
+        source,
+        InputSource::File(PathBuf::from("/tmp/inputs/2015/day-01.txt"))
+    );
+}
+
+#[test]
+fn test_input_source_read() {
+    let source = InputSource::File(PathBuf::from("input/2015/day-01.txt"));
+    let contents = source.read().unwrap();
+    assert!(contents.starts_with('('));
+}
+
+#[test]
+fn test_input_source_read_missing_file() {
+    let source = InputSource::File(PathBuf::from("input/2015/day-99.txt"));
+    let err = source.read().unwrap_err();
+    assert_eq!(err.input(), &source);
+    assert!(err.to_string().contains("input/2015/day-99.txt"));
+}
+
+#[test]
+fn test_options_format() {
+    let options = Options::parse(Vec::<String>::new()).unwrap();
+    assert_eq!(options.format, Format::Text);
+
+    let options = Options::parse(vec!["--format", "json"]).unwrap();
+    assert_eq!(options.format, Format::Json);
+
+    assert!(Options::parse(vec!["--format", "yaml"]).is_err());
+}
+
+#[test]
+fn test_options_timings() {
+    let options = Options::parse(Vec::<String>::new()).unwrap();
+    assert!(!options.timings);
+    assert_eq!(options.slowest, None);
+
+    let options = Options::parse(vec!["--timings"]).unwrap();
+    assert!(options.timings);
+    assert_eq!(options.slowest, None);
+
+    let options = Options::parse(vec!["--slowest", "3"]).unwrap();
+    assert!(options.timings);
+    assert_eq!(options.slowest, Some(3));
+
+    assert!(Options::parse(vec!["--slowest", "many"]).is_err());
+}
+
+#[test]
+fn test_options_timings_json() {
+    assert!(Options::parse(vec!["--format", "json", "--timings"]).is_err());
+    assert!(Options::parse(vec!["--slowest", "3", "--format=json"]).is_err());
+}
+
+#[test]
+fn test_options_lenient() {
+    let options = Options::parse(Vec::<String>::new()).unwrap();
+    assert!(!options.lenient);
+
+    let options = Options::parse(vec!["--lenient"]).unwrap();
+    assert!(options.lenient);
+
+    let options = Options::parse(vec!["-l"]).unwrap();
+    assert!(options.lenient);
+}
+
+#[test]
+fn test_options_check() {
+    let options = Options::parse(Vec::<String>::new()).unwrap();
+    assert!(!options.check);
+    assert_eq!(options.answers_dir(), Path::new("input"));
+
+    let options = Options::parse(vec!["--check", "--input-dir", "my-input"]).unwrap();
+    assert_eq!(options.answers_dir(), Path::new("my-input"));
+
+    let options = Options::parse(vec!["--check", "--answers-dir", "my-answers"]).unwrap();
+    assert!(options.check);
+    assert_eq!(options.answers_dir(), Path::new("my-answers"));
+}
+
+#[test]
+fn test_options_help() {
+    let options = Options::parse(vec!["--help"]).unwrap();
+    assert!(options.help);
+}
+
+#[test]
+fn test_options_bad_input() {
+    assert!(Options::parse(vec!["--pancakes"]).is_err());
+    assert!(Options::parse(vec!["--day"]).is_err());
+    assert!(Options::parse(vec!["--input"]).is_err());
+    assert!(Options::parse(vec!["--day", "six"]).is_err());
+    assert!(Options::parse(vec!["--part", "3"]).is_err());
+    assert!(Options::parse(vec!["--part", "0"]).is_err());
+}
+
+#[test]
+fn test_selection() {
+    let selection = Selection::from_str("all").unwrap();
+    assert!(selection.contains(0));
+    assert!(selection.contains(25));
+
+    let selection = Selection::from_str("1,3,10..=12").unwrap();
+    assert!(selection.contains(1));
+    assert!(!selection.contains(2));
+    assert!(selection.contains(3));
+    assert!(selection.contains(11));
+    assert!(!selection.contains(13));
+}
+
+#[test]
+fn test_selection_bad_input() {
+    assert!(Selection::from_str("").is_err());
+    assert!(Selection::from_str("10..=1").is_err());
+    assert!(Selection::from_str("1..1").is_err());
+    assert!(Selection::from_str("1..").is_err());
+    assert!(Selection::from_str("-1").is_err());
+}
@@ -1 +1,2 @@
 mod year_2015;
+mod year_2016;
@@ -0,0 +1,97 @@
+use std::fs;
+
+use advent_of_code::solution::{Answers, Part};
+use advent_of_code::{puzzle, puzzles};
+
+fn solve_input_file(year: u16, day: u8) -> Answers {
+    let path = format!("input/{}/day-{:02}.txt", year, day);
+    let contents = fs::read_to_string(path).expect("Failed to read file to string.");
+
+    let puzzle = puzzle(year, day).expect("Puzzle is not registered.");
+    puzzle.solve(&contents).expect("Failed to solve puzzle.")
+}
+
+#[test]
+fn test_puzzles_ordered_by_year_and_day() {
+    let keys: Vec<(u16, u8)> = puzzles().map(|puzzle| (puzzle.year, puzzle.day)).collect();
+
+    let mut sorted = keys.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    assert_eq!(keys, sorted);
+}
+
+#[test]
+fn test_puzzle_not_registered() {
+    assert!(puzzle(2014, 1).is_none());
+    assert!(puzzle(2015, 0).is_none());
+}
+
+#[test]
+fn test_puzzle_title() {
+    let puzzle = puzzle(2015, 7).unwrap();
+    assert_eq!(puzzle.title, "Some Assembly Required");
+}
+
+#[test]
+fn test_puzzles_span_years() {
+    let puzzle = puzzle(2016, 1).unwrap();
+    assert_eq!(puzzle.title, "No Time for a Taxicab");
+
+    let last_2015 = puzzles().position(|puzzle| (puzzle.year, puzzle.day) == (2015, 25));
+    let first_2016 = puzzles().position(|puzzle| (puzzle.year, puzzle.day) == (2016, 1));
+    assert_eq!(last_2015.map(|i| i + 1), first_2016);
+}
+
+#[test]
+fn test_solve_2016_01() {
+    let puzzle = puzzle(2016, 1).unwrap();
+    let answers = puzzle.solve("R8, R4, R4, R8\n").unwrap();
+    assert_eq!(answers.part_one, "8");
+    assert_eq!(answers.part_two, "4");
+}
+
+#[test]
+fn test_solve_2015_01_input_file() {
+    let answers = solve_input_file(2015, 1);
+    assert_eq!(answers.part_one, "280");
+    assert_eq!(answers.part_two, "1797");
+}
+
+#[test]
+fn test_solve_2015_07_input_file() {
+    let answers = solve_input_file(2015, 7);
+    assert_eq!(answers.part_one, "16076");
+    assert_eq!(answers.part_two, "2797");
+}
+
+#[test]
+fn test_solve_2015_11_input_file() {
+    let answers = solve_input_file(2015, 11);
+    assert_eq!(answers.part_one, "cqjxxyzz");
+    assert_eq!(answers.part_two, "cqkaabcc");
+}
+
+#[test]
+fn test_solve_2015_13_input_file() {
+    let answers = solve_input_file(2015, 13);
+    assert_eq!(answers.part_one, "664");
+    assert_eq!(answers.part_two, "640");
+}
+
+#[test]
+fn test_solve_bad_input() {
+    let puzzle = puzzle(2015, 12).unwrap();
+    assert!(puzzle.solve("{pancakes").is_err());
+}
+
+#[test]
+fn test_solve_parts_failed_part() {
+    let puzzle = puzzle(2016, 1).unwrap();
+    let solved = puzzle.solve_parts("R2, L3\n", &Part::ALL).unwrap();
+    assert_eq!(solved.answers.len(), 2);
+    assert_eq!(solved.answers[0].value.as_deref().unwrap(), "5");
+    assert!(solved.answers[1].value.is_err());
+
+    assert!(puzzle.solve("R2, L3\n").is_err());
+}
@@ -0,0 +1,155 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use advent_of_code::report::{slowest, write_records, write_timings, Format, Record, Timing};
+use advent_of_code::solution::Part;
+
+#[test]
+fn test_format_from_str() {
+    assert_eq!(Format::from_str("text").unwrap(), Format::Text);
+    assert_eq!(Format::from_str("json").unwrap(), Format::Json);
+    assert!(Format::from_str("pancakes").is_err());
+}
+
+#[test]
+fn test_write_records_json() {
+    let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+    let solved = puzzle.solve_parts("()())", &Part::ALL).unwrap();
+    let records = Record::answers(puzzle, &solved);
+
+    let mut output = Vec::new();
+    write_records(&mut output, Format::Json, puzzle, &records).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["year"], 2015);
+    assert_eq!(lines[0]["day"], 1);
+    assert_eq!(lines[0]["part"], 1);
+    assert_eq!(lines[0]["answer"], "-1");
+    assert!(lines[0]["parse_ms"].is_f64());
+    assert!(lines[0]["elapsed_ms"].is_f64());
+    assert_eq!(lines[1]["part"], 2);
+    assert_eq!(lines[1]["answer"], "5");
+    assert!(lines[1].get("error").is_none());
+}
+
+#[test]
+fn test_write_records_text() {
+    let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+    let solved = puzzle.solve_parts("()())", &[Part::Two]).unwrap();
+    let mut records = Record::answers(puzzle, &solved);
+    records.push(Record::error(puzzle, Part::One, &"pancakes"));
+
+    let mut output = Vec::new();
+    write_records(&mut output, Format::Text, puzzle, &records).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(
+        output,
+        "Advent of Code - Year 2015 Day 1: Not Quite Lisp\nPart Two: 5\nPart One: failed - pancakes\n"
+    );
+}
+
+#[test]
+fn test_record_error() {
+    let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+    let record = Record::error(puzzle, Part::Two, &"no answer");
+    assert!(record.is_error());
+    assert_eq!(record.answer, None);
+    assert_eq!(record.elapsed_ms, None);
+}
+
+#[test]
+fn test_timing() {
+    let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+    let solved = puzzle.solve_parts("()())", &[Part::Two]).unwrap();
+
+    let timing = Timing::new(puzzle, &solved);
+    assert_eq!(timing.parse, solved.parse_elapsed);
+    assert_eq!(timing.part_one, None);
+    assert_eq!(timing.part_two, Some(solved.answers[0].elapsed));
+    assert_eq!(
+        timing.total(),
+        solved.parse_elapsed + solved.answers[0].elapsed
+    );
+}
+
+#[test]
+fn test_slowest() {
+    let timing = |day, millis| Timing {
+        year: 2015,
+        day,
+        title: "",
+        parse: Duration::from_millis(1),
+        part_one: Some(Duration::from_millis(millis)),
+        part_two: None,
+    };
+
+    let mut timings = vec![timing(1, 5), timing(2, 20), timing(3, 10)];
+    slowest(&mut timings, 5);
+    let days: Vec<u8> = timings.iter().map(|timing| timing.day).collect();
+    assert_eq!(days, vec![2, 3, 1]);
+
+    slowest(&mut timings, 0);
+    assert!(timings.is_empty());
+}
+
+#[test]
+fn test_write_timings() {
+    let timings = vec![Timing {
+        year: 2015,
+        day: 4,
+        title: "The Ideal Stocking Stuffer",
+        parse: Duration::from_micros(3),
+        part_one: Some(Duration::from_millis(150)),
+        part_two: None,
+    }];
+
+    let mut output = Vec::new();
+    write_timings(&mut output, &timings).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Year  Day  Title"));
+    assert!(lines[1].starts_with("2015    4  The Ideal Stocking Stuffer"));
+    assert!(lines[1].contains("3 µs"));
+    assert!(lines[1].contains("150.000 ms"));
+    assert!(lines[1].contains(" - "));
+    assert!(lines[1].ends_with("150.003 ms"));
+}
+
+#[test]
+fn test_record_answers_part_failed() {
+    let puzzle = advent_of_code::puzzle(2016, 1).unwrap();
+    let solved = puzzle.solve_parts("R2, L3\n", &Part::ALL).unwrap();
+    let records = Record::answers(puzzle, &solved);
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].part, Part::One);
+    assert_eq!(records[0].answer.as_deref(), Some("5"));
+    assert!(!records[0].is_error());
+    assert_eq!(records[1].part, Part::Two);
+    assert_eq!(records[1].answer, None);
+    assert!(records[1].is_error());
+
+    let mut output = Vec::new();
+    write_records(&mut output, Format::Json, puzzle, &records).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines[0]["answer"], "5");
+    assert!(lines[0].get("error").is_none());
+    assert!(lines[1].get("answer").is_none());
+    assert!(lines[1]["error"].is_string());
+
+    let timing = Timing::new(puzzle, &solved);
+    assert!(timing.part_one.is_some());
+    assert_eq!(timing.part_two, None);
+}
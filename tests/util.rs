@@ -0,0 +1,87 @@
+use std::error::Error;
+
+use advent_of_code::solution::Part;
+use advent_of_code::util::{parse_lines, parse_lines_lenient, ParseError};
+use advent_of_code::year_2015::day_06::LightInstruction;
+
+const INSTRUCTIONS: &str = "turn on 0,0 through 999,999
+toggle 0,0 through 999,0
+
+turn of 499,499 through 500,500
+turn off 499,499 through 500,500
+switch 1,1 through 2,2";
+
+#[test]
+fn test_parse_lines() {
+    let instructions =
+        parse_lines::<LightInstruction>("turn on 0,0 through 1,1\n\ntoggle 1,1 through 2,2\n");
+    assert_eq!(instructions.unwrap().len(), 2);
+
+    assert!(parse_lines::<LightInstruction>("").unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_lines_first_bad_line() {
+    let err = parse_lines::<LightInstruction>(INSTRUCTIONS).unwrap_err();
+    assert_eq!(err.line(), "turn of 499,499 through 500,500");
+    assert_eq!(err.line_number(), Some(4));
+    assert!(err
+        .to_string()
+        .starts_with("line 4: 'turn of 499,499 through 500,500'"));
+}
+
+#[test]
+fn test_parse_lines_lenient() {
+    let parsed = parse_lines_lenient::<LightInstruction>(INSTRUCTIONS);
+    assert_eq!(parsed.items.len(), 3);
+
+    let rejected: Vec<(Option<usize>, &str)> = parsed
+        .rejected
+        .iter()
+        .map(|err| (err.line_number(), err.line()))
+        .collect();
+    assert_eq!(
+        rejected,
+        vec![
+            (Some(4), "turn of 499,499 through 500,500"),
+            (Some(6), "switch 1,1 through 2,2"),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_lines_numbers() {
+    assert_eq!(parse_lines::<u32>("1\n20\n300").unwrap(), vec![1, 20, 300]);
+
+    let err = parse_lines::<u8>("1\n300").unwrap_err();
+    assert_eq!(err.line_number(), Some(2));
+    assert_eq!(err.expected(), "u8");
+    assert!(err.source().is_some());
+}
+
+#[test]
+fn test_solution_parse_rejects_bad_line() {
+    let puzzle = advent_of_code::puzzle(2015, 6).unwrap();
+    let err = puzzle.solve_parts(INSTRUCTIONS, &[]).unwrap_err();
+    assert!(err.to_string().contains("line 4"));
+}
+
+#[test]
+fn test_solution_parse_lenient_skips_bad_lines() {
+    let puzzle = advent_of_code::puzzle(2015, 6).unwrap();
+    let solved = puzzle
+        .solve_parts_lenient(INSTRUCTIONS, &[Part::One])
+        .unwrap();
+    assert_eq!(solved.answers[0].value.as_deref().unwrap(), "998996");
+
+    let line_numbers: Vec<Option<usize>> = solved
+        .rejected
+        .iter()
+        .map(ParseError::line_number)
+        .collect();
+    assert_eq!(line_numbers, vec![Some(4), Some(6)]);
+
+    let puzzle = advent_of_code::puzzle(2015, 1).unwrap();
+    let solved = puzzle.solve_parts_lenient("()())", &Part::ALL).unwrap();
+    assert!(solved.rejected.is_empty());
+}
@@ -1,6 +1,10 @@
 use std::fs;
+use std::fs::File;
+use std::io::BufReader;
 
-use advent_of_code::year_2015::day_01::{floor, position_to_floor};
+use advent_of_code::year_2015::day_01::{
+    floor, floor_from_reader, position_to_floor, position_to_floor_from_reader,
+};
 
 #[test]
 fn test_floor_bad_input() {
@@ -53,3 +57,31 @@ fn test_position_input_file() {
     let position = position_to_floor(&contents, -1);
     assert_eq!(position, Some(1797));
 }
+
+#[test]
+fn test_floor_from_reader_input_file() {
+    let file = File::open("input/2015/day-01.txt").expect("Failed to open file.");
+
+    let floor = floor_from_reader(BufReader::new(file)).unwrap();
+    assert_eq!(floor, 280);
+}
+
+#[test]
+fn test_position_from_reader_input_file() {
+    let file = File::open("input/2015/day-01.txt").expect("Failed to open file.");
+
+    let position = position_to_floor_from_reader(BufReader::new(file), -1).unwrap();
+    assert_eq!(position, Some(1797));
+}
+
+#[test]
+fn test_floor_from_reader_small_buffer() {
+    let contents = fs::read("input/2015/day-01.txt").expect("Failed to read file.");
+
+    let reader = BufReader::with_capacity(7, &contents[..]);
+    assert_eq!(floor_from_reader(reader).unwrap(), floor(&contents));
+
+    let reader = BufReader::with_capacity(7, &contents[..]);
+    let position = position_to_floor_from_reader(reader, -1).unwrap();
+    assert_eq!(position, Some(1797));
+}
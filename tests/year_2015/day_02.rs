@@ -1,29 +1,28 @@
 use std::fs;
 use std::str::FromStr;
 
+use advent_of_code::util::parse_lines;
 use advent_of_code::year_2015::day_02::Present;
 
 #[test]
 fn test_present_from_str_bad_input() {
-    match Present::from_str("hjhjxjhjhxikjk") {
-        Ok(_) => panic!(),
-        Err(_) => (),
-    }
+    assert!(Present::from_str("hjhjxjhjhxikjk").is_err());
 
-    match Present::from_str("1x1") {
-        Ok(_) => panic!(),
-        Err(_) => (),
-    }
+    assert!(Present::from_str("1x1").is_err());
 
-    match Present::from_str("1x1x1x1") {
-        Ok(_) => panic!(),
-        Err(_) => (),
-    }
+    assert!(Present::from_str("1x1x1x1").is_err());
 
-    match Present::from_str("-1x-1x-1") {
-        Ok(_) => panic!(),
-        Err(_) => (),
-    }
+    assert!(Present::from_str("-1x-1x-1").is_err());
+}
+
+#[test]
+fn test_present_from_str_overflow() {
+    let err = Present::from_str("99999999999x1x1").unwrap_err();
+    assert_eq!(err.line(), "99999999999x1x1");
+    assert_eq!(err.expected(), "{length}x{width}x{height}");
+    assert!(err
+        .to_string()
+        .starts_with("'99999999999x1x1' was not in format '{length}x{width}x{height}': "));
 }
 
 #[test]
@@ -38,11 +37,7 @@ fn test_wrapping_paper_needed_input_file() {
     let contents =
         fs::read_to_string("input/2015/day-02.txt").expect("Failed to read file to string.");
 
-    let presents: Vec<Present> = contents
-        .lines()
-        .map(|s| Present::from_str(s))
-        .filter_map(Result::ok)
-        .collect();
+    let presents: Vec<Present> = parse_lines(&contents).unwrap();
 
     let wrapping_paper_needed: u32 = presents.iter().map(Present::wrapping_paper_needed).sum();
 
@@ -54,11 +49,7 @@ fn test_ribbon_needed_input_file() {
     let contents =
         fs::read_to_string("input/2015/day-02.txt").expect("Failed to read file to string.");
 
-    let presents: Vec<Present> = contents
-        .lines()
-        .map(|s| Present::from_str(s))
-        .filter_map(Result::ok)
-        .collect();
+    let presents: Vec<Present> = parse_lines(&contents).unwrap();
 
     let ribbon_needed: u32 = presents.iter().map(Present::ribbon_needed).sum();
 
@@ -1,13 +1,21 @@
+use std::fs;
+
 use advent_of_code::year_2015::day_04::find_number;
 
 #[test]
-fn test_find_number_with_five_leading_zeroes_input() {
-    let number = find_number(b"iwrupvqb", 5);
+fn test_find_number_with_five_leading_zeroes_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-04.txt").expect("Failed to read file to string.");
+
+    let number = find_number(contents.trim().as_bytes(), 5);
     assert_eq!(number, Some(346386));
 }
 
 #[test]
-fn test_find_number_with_six_leading_zeroes_input() {
-    let number = find_number(b"iwrupvqb", 6);
+fn test_find_number_with_six_leading_zeroes_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-04.txt").expect("Failed to read file to string.");
+
+    let number = find_number(contents.trim().as_bytes(), 6);
     assert_eq!(number, Some(9958218));
 }
@@ -1,47 +1,42 @@
+use std::error::Error;
 use std::fs;
+use std::fs::File;
+use std::io::BufReader;
 use std::str::FromStr;
 
-use advent_of_code::year_2015::day_06::{AdjustableBulb, LightGrid, LightInstruction, SimpleBulb};
+use advent_of_code::util::{parse_lines, ReadError};
+use advent_of_code::year_2015::day_06::{
+    AdjustableBulb, Bulb, Channel, ColorBulb, DimmerBulb, InstructionError, LightGrid,
+    LightInstruction, LightRecorder, Operation, Point, SimpleBulb, Storage,
+};
 
 #[test]
 fn test_light_grid_follow_instruction_bad_input() {
-    match LightInstruction::from_str("pancakes") {
-        Ok(_) => panic!(),
-        Err(_) => (),
-    };
+    assert!(LightInstruction::from_str("pancakes").is_err());
 
-    match LightInstruction::from_str("switch 0,0 through 999,0") {
-        Ok(_) => panic!(),
-        Err(_) => (),
-    };
+    assert!(LightInstruction::from_str("switch 0,0 through 999,0").is_err());
 
-    match LightInstruction::from_str("switch -1,0 through 999,0") {
-        Ok(_) => panic!(),
-        Err(_) => (),
-    };
+    assert!(LightInstruction::from_str("switch -1,0 through 999,0").is_err());
 
-    match LightInstruction::from_str("turn on 1000,1001 through 1000,1002") {
-        Ok(_) => panic!(),
-        Err(_) => (),
-    };
+    assert!(LightInstruction::from_str("turn on 1000,1001 through 1000,1002").is_err());
 }
 
 #[test]
 fn test_light_grid_apply_operation() {
     let mut grid = LightGrid::<SimpleBulb>::new();
     if let Ok(instruction) = LightInstruction::from_str("turn on 0,0 through 999,999") {
-        grid.apply_operation(&instruction);
+        grid.apply_operation(&instruction).unwrap();
     };
     assert_eq!(grid.total_brightness(), 1000000);
 
     if let Ok(instruction) = LightInstruction::from_str("turn off 499,499 through 500,500") {
-        grid.apply_operation(&instruction);
+        grid.apply_operation(&instruction).unwrap();
     };
     assert_eq!(grid.total_brightness(), 999996);
 
     let mut grid = LightGrid::<SimpleBulb>::new();
     if let Ok(instruction) = LightInstruction::from_str("toggle 0,0 through 999,0") {
-        grid.apply_operation(&instruction);
+        grid.apply_operation(&instruction).unwrap();
     };
     assert_eq!(grid.total_brightness(), 1000);
 }
@@ -50,13 +45,13 @@ fn test_light_grid_apply_operation() {
 fn test_light_grid_increase_brightness() {
     let mut grid = LightGrid::<AdjustableBulb>::new();
     if let Ok(instruction) = LightInstruction::from_str("turn on 0,0 through 0,0") {
-        grid.apply_operation(&instruction);
+        grid.apply_operation(&instruction).unwrap();
     };
     assert_eq!(grid.total_brightness(), 1);
 
     let mut grid = LightGrid::<AdjustableBulb>::new();
     if let Ok(instruction) = LightInstruction::from_str("toggle 0,0 through 999,999") {
-        grid.apply_operation(&instruction);
+        grid.apply_operation(&instruction).unwrap();
     };
     assert_eq!(grid.total_brightness(), 2000000);
 }
@@ -67,11 +62,10 @@ fn test_simple_bulbs_input_file() {
         fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
 
     let mut grid = LightGrid::<SimpleBulb>::new();
-    contents
-        .lines()
-        .map(|s| LightInstruction::from_str(s))
-        .filter_map(Result::ok)
-        .for_each(|instruction| grid.apply_operation(&instruction));
+    parse_lines::<LightInstruction>(&contents)
+        .unwrap()
+        .into_iter()
+        .for_each(|instruction| grid.apply_operation(&instruction).unwrap());
     let count = grid.total_brightness();
     assert_eq!(count, 543903);
 }
@@ -82,11 +76,593 @@ fn test_adjustable_bulbs_input_file() {
         fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
 
     let mut grid = LightGrid::<AdjustableBulb>::new();
-    contents
-        .lines()
-        .map(|s| LightInstruction::from_str(s))
-        .filter_map(Result::ok)
-        .for_each(|instruction| grid.apply_operation(&instruction));
+    parse_lines::<LightInstruction>(&contents)
+        .unwrap()
+        .into_iter()
+        .for_each(|instruction| grid.apply_operation(&instruction).unwrap());
     let brightness = grid.total_brightness();
     assert_eq!(brightness, 14687245);
 }
+
+#[test]
+fn test_simple_bulbs_from_reader_input_file() {
+    let file = File::open("input/2015/day-06.txt").expect("Failed to open file.");
+
+    let mut grid = LightGrid::<SimpleBulb>::new();
+    let applied = grid
+        .apply_operations_from_reader(BufReader::new(file))
+        .unwrap();
+    assert_eq!(applied, 300);
+    assert_eq!(grid.total_brightness(), 543903);
+}
+
+#[test]
+fn test_apply_operations_from_reader_bad_line() {
+    let instructions = "turn on 0,0 through 9,9\nturn of 0,0 through 9,9\ntoggle 0,0 through 9,9\n";
+
+    let mut grid = LightGrid::<SimpleBulb>::new();
+    match grid.apply_operations_from_reader(instructions.as_bytes()) {
+        Err(ReadError::Parse(err)) => assert_eq!(err.line_number(), Some(2)),
+        _ => panic!("expected the second line to be rejected"),
+    }
+    assert_eq!(grid.total_brightness(), 100);
+}
+
+#[test]
+fn test_compressed_light_grid_apply_operation() {
+    let mut grid = LightGrid::<AdjustableBulb>::with_storage(Storage::Compressed);
+    assert_eq!(grid.storage(), Storage::Compressed);
+    assert_eq!(grid.total_brightness(), 0);
+
+    grid.apply_operation(&LightInstruction::from_str("toggle 0,0 through 999,999").unwrap())
+        .unwrap();
+    assert_eq!(grid.total_brightness(), 2000000);
+
+    grid.apply_operation(&LightInstruction::from_str("turn off 10,20 through 10,20").unwrap())
+        .unwrap();
+    grid.apply_operation(&LightInstruction::from_str("turn on 5,5 through 14,24").unwrap())
+        .unwrap();
+    assert_eq!(grid.total_brightness(), 2000199);
+    assert_eq!(grid.bulb(&Point { x: 10, y: 20 }).brightness(), 2);
+    assert_eq!(grid.bulb(&Point { x: 14, y: 24 }).brightness(), 3);
+    assert_eq!(grid.bulb(&Point { x: 15, y: 24 }).brightness(), 2);
+    assert_eq!(grid.bulb(&Point { x: 999, y: 999 }).brightness(), 2);
+}
+
+#[test]
+fn test_compressed_light_grid_total_brightness_beyond_i32() {
+    let instruction = LightInstruction::from_str("toggle 0,0 through 999,999").unwrap();
+    let mut recorder = LightRecorder::new(LightGrid::<AdjustableBulb>::with_storage(
+        Storage::Compressed,
+    ));
+    for _ in 0..1100 {
+        recorder.record(instruction.clone()).unwrap();
+    }
+
+    assert_eq!(recorder.grid().total_brightness(), 2_200_000_000);
+    assert_eq!(recorder.brightness_history()[1099], 2_200_000_000);
+    assert_eq!(recorder.brightness_history()[1073], 2_148_000_000);
+}
+
+#[test]
+fn test_light_grid_rejects_invalid_instructions() {
+    for &storage in &[Storage::Dense, Storage::Compressed] {
+        let mut grid = LightGrid::<SimpleBulb>::with_size_and_storage(10, 5, storage);
+        let instruction = LightInstruction::from_str("turn on 0,0 through 9,5").unwrap();
+        assert_eq!(
+            grid.apply_operation(&instruction),
+            Err(InstructionError::OutsideGrid {
+                point: Point { x: 9, y: 5 },
+                width: 10,
+                height: 5,
+            })
+        );
+
+        let instruction = LightInstruction::from_str("toggle 3,4 through 3,1").unwrap();
+        let err = grid.apply_operation(&instruction).unwrap_err();
+        assert_eq!(
+            err,
+            InstructionError::Inverted {
+                start_point: Point { x: 3, y: 4 },
+                end_point: Point { x: 3, y: 1 },
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "the start point 3,4 is after the end point 3,1"
+        );
+        assert_eq!(grid.total_brightness(), 0);
+    }
+}
+
+#[test]
+fn test_light_grid_with_size() {
+    for &storage in &[Storage::Dense, Storage::Compressed] {
+        let mut grid = LightGrid::<AdjustableBulb>::with_size_and_storage(3, 2000, storage);
+        assert_eq!((grid.width(), grid.height()), (3, 2000));
+
+        let instruction = grid
+            .parse_instruction("toggle 1,1000 through 2,1999")
+            .unwrap();
+        grid.apply_operation(&instruction).unwrap();
+        assert_eq!(grid.total_brightness(), 4000);
+        assert_eq!(grid.bulb(&Point { x: 2, y: 1999 }).brightness(), 2);
+    }
+}
+
+#[test]
+fn test_light_grid_large_size() {
+    let mut grid =
+        LightGrid::<SimpleBulb>::with_size_and_storage(50_000, 50_000, Storage::Compressed);
+    let instruction = grid
+        .parse_instruction("turn on 0,0 through 49999,49999")
+        .unwrap();
+    grid.apply_operation(&instruction).unwrap();
+    assert_eq!(grid.total_brightness(), 2_500_000_000);
+
+    let mut grid =
+        LightGrid::<AdjustableBulb>::with_size_and_storage(65_536, 65_536, Storage::Compressed);
+    let instruction = grid
+        .parse_instruction("set 2147483647 0,0 through 65535,65535")
+        .unwrap();
+    grid.apply_operation(&instruction).unwrap();
+    assert_eq!(grid.total_brightness(), (1_i64 << 32) * i64::from(i32::MAX));
+}
+
+#[test]
+#[should_panic(expected = "the grid must have at most 4294967296 lights")]
+fn test_light_grid_too_large() {
+    let _ = LightGrid::<SimpleBulb>::with_size_and_storage(65_536, 65_537, Storage::Compressed);
+}
+
+#[test]
+fn test_light_grid_parse_instruction_follows_size() {
+    let grid = LightGrid::<SimpleBulb>::with_size(10, 100000);
+    assert!(grid
+        .parse_instruction("turn on 0,0 through 9,99999")
+        .is_ok());
+    assert!(grid.parse_instruction("turn on 0,0 through 10,9").is_err());
+    assert!(grid
+        .parse_instruction("turn on 0,0 through 9,100000")
+        .is_err());
+
+    let err = grid
+        .parse_instruction("turn on 5,0 through 4,9")
+        .unwrap_err();
+    assert!(err.source().is_some());
+
+    let mut grid = LightGrid::<SimpleBulb>::with_size(10, 10);
+    let instructions = "turn on 0,0 through 9,9\nturn off 0,0 through 10,9\n";
+    match grid.apply_operations_from_reader(instructions.as_bytes()) {
+        Err(ReadError::Parse(err)) => assert_eq!(err.line_number(), Some(2)),
+        _ => panic!("expected the second line to be rejected"),
+    }
+    assert_eq!(grid.total_brightness(), 100);
+}
+
+#[test]
+fn test_compressed_light_grid_matches_dense_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
+    let instructions = parse_lines::<LightInstruction>(&contents).unwrap();
+
+    let mut dense = LightGrid::<AdjustableBulb>::new();
+    let mut compressed = LightGrid::<AdjustableBulb>::with_storage(Storage::Compressed);
+    for instruction in &instructions {
+        dense.apply_operation(instruction).unwrap();
+        compressed.apply_operation(instruction).unwrap();
+    }
+    assert_eq!(compressed.total_brightness(), 14687245);
+
+    for x in (0..1000).step_by(7) {
+        for y in (0..1000).step_by(11) {
+            let point = Point { x, y };
+            assert_eq!(
+                dense.bulb(&point).brightness(),
+                compressed.bulb(&point).brightness()
+            );
+        }
+    }
+
+    let mut grid = LightGrid::<SimpleBulb>::with_storage(Storage::Compressed);
+    instructions
+        .iter()
+        .for_each(|instruction| grid.apply_operation(instruction).unwrap());
+    assert_eq!(grid.total_brightness(), 543903);
+}
+
+#[test]
+fn test_light_grid_write_pgm_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
+    let instructions = parse_lines::<LightInstruction>(&contents).unwrap();
+
+    let mut grid = LightGrid::<SimpleBulb>::new();
+    instructions
+        .iter()
+        .for_each(|instruction| grid.apply_operation(instruction).unwrap());
+    let mut pgm = Vec::new();
+    grid.write_pgm(&mut pgm).unwrap();
+    let header = b"P5\n1000 1000\n1\n";
+    assert!(pgm.starts_with(header));
+    assert_eq!(pgm.len(), header.len() + 1000000);
+    assert_eq!(
+        pgm[header.len()..]
+            .iter()
+            .map(|&p| i32::from(p))
+            .sum::<i32>(),
+        543903
+    );
+
+    let mut dense = LightGrid::<AdjustableBulb>::new();
+    let mut compressed = LightGrid::<AdjustableBulb>::with_storage(Storage::Compressed);
+    for instruction in &instructions {
+        dense.apply_operation(instruction).unwrap();
+        compressed.apply_operation(instruction).unwrap();
+    }
+    let (mut dense_pgm, mut compressed_pgm) = (Vec::new(), Vec::new());
+    dense.write_pgm(&mut dense_pgm).unwrap();
+    compressed.write_pgm(&mut compressed_pgm).unwrap();
+    assert_eq!(dense_pgm, compressed_pgm);
+    assert_eq!(dense.render_ascii(80, 40), compressed.render_ascii(80, 40));
+}
+
+#[test]
+fn test_light_grid_write_wide_samples() {
+    let mut grid = LightGrid::<AdjustableBulb>::with_size(2, 1);
+    let instruction = grid.parse_instruction("toggle 0,0 through 0,0").unwrap();
+    for _ in 0..150 {
+        grid.apply_operation(&instruction).unwrap();
+    }
+
+    let mut pgm = Vec::new();
+    grid.write_pgm(&mut pgm).unwrap();
+    assert_eq!(pgm, b"P5\n2 1\n300\n\x01\x2c\x00\x00");
+
+    let mut ppm = Vec::new();
+    grid.write_ppm(&mut ppm).unwrap();
+    assert_eq!(
+        ppm,
+        b"P6\n2 1\n300\n\x01\x2c\x01\x2c\x01\x2c\x00\x00\x00\x00\x00\x00"
+    );
+}
+
+#[test]
+fn test_light_grid_render_ascii() {
+    let mut grid = LightGrid::<AdjustableBulb>::with_size(5, 3);
+    assert_eq!(grid.render_ascii(10, 10), "     \n     \n     \n");
+
+    let instruction = grid.parse_instruction("toggle 0,0 through 1,2").unwrap();
+    grid.apply_operation(&instruction).unwrap();
+    let instruction = grid.parse_instruction("turn on 4,2 through 4,2").unwrap();
+    grid.apply_operation(&instruction).unwrap();
+    assert_eq!(grid.render_ascii(5, 3), "@@   \n@@   \n@@  +\n");
+    assert_eq!(grid.render_ascii(2, 1), "*.\n");
+}
+
+#[test]
+fn test_light_recorder_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
+    let instructions = parse_lines::<LightInstruction>(&contents).unwrap();
+
+    for &storage in &[Storage::Dense, Storage::Compressed] {
+        let mut recorder = LightRecorder::new(LightGrid::<AdjustableBulb>::with_storage(storage));
+        for instruction in &instructions {
+            recorder.record(instruction.clone()).unwrap();
+        }
+        assert_eq!(recorder.instructions().len(), 300);
+        assert_eq!(recorder.brightness_history().last(), Some(&14687245));
+        assert_eq!(recorder.grid().total_brightness(), 14687245);
+
+        let before_last = recorder.brightness_history()[199];
+        assert_eq!(recorder.undo(100), 100);
+        assert_eq!(recorder.grid().total_brightness(), before_last);
+        assert_eq!(recorder.brightness_history().len(), 200);
+    }
+}
+
+#[test]
+fn test_light_recorder_history() {
+    let mut recorder = LightRecorder::new(LightGrid::<SimpleBulb>::with_size(10, 10));
+    assert_eq!(recorder.undo(1), 0);
+
+    let instructions = [
+        "turn on 0,0 through 4,4",
+        "toggle 2,2 through 9,9",
+        "turn off 0,0 through 1,1",
+    ];
+    for instruction in &instructions {
+        recorder
+            .record(LightInstruction::from_str(instruction).unwrap())
+            .unwrap();
+    }
+    assert_eq!(recorder.brightness_history(), &[25, 71, 67]);
+
+    let instruction = LightInstruction::from_str("turn on 0,0 through 10,0").unwrap();
+    assert!(recorder.record(instruction).is_err());
+    assert_eq!(recorder.instructions().len(), 3);
+
+    assert_eq!(recorder.last_change(&Point { x: 0, y: 0 }), Some(2));
+    assert_eq!(recorder.last_change(&Point { x: 3, y: 3 }), Some(1));
+    assert_eq!(recorder.last_change(&Point { x: 4, y: 0 }), Some(0));
+    assert_eq!(recorder.last_change(&Point { x: 0, y: 9 }), None);
+
+    assert_eq!(recorder.undo(1), 1);
+    assert_eq!(recorder.last_change(&Point { x: 0, y: 0 }), Some(0));
+    assert_eq!(recorder.grid().total_brightness(), 71);
+    assert_eq!(recorder.grid().bulb(&Point { x: 3, y: 3 }).brightness(), 0);
+}
+
+#[test]
+fn test_light_recorder_starts_from_grid() {
+    let mut grid = LightGrid::<AdjustableBulb>::with_size(2, 2);
+    let instruction = grid.parse_instruction("toggle 0,0 through 1,1").unwrap();
+    grid.apply_operation(&instruction).unwrap();
+
+    let mut recorder = LightRecorder::new(grid);
+    let instruction = LightInstruction::from_str("turn off 0,0 through 0,1").unwrap();
+    assert_eq!(recorder.record(instruction).unwrap(), 6);
+    assert_eq!(recorder.undo(1), 1);
+    assert_eq!(recorder.grid().total_brightness(), 8);
+}
+
+#[test]
+fn test_light_regions_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-06.txt").expect("Failed to read file to string.");
+    let mut grid = LightGrid::<AdjustableBulb>::with_storage(Storage::Compressed);
+    parse_lines::<LightInstruction>(&contents)
+        .unwrap()
+        .iter()
+        .for_each(|instruction| grid.apply_operation(instruction).unwrap());
+
+    let regions = grid.regions();
+    let (origin, corner) = (Point { x: 0, y: 0 }, Point { x: 999, y: 999 });
+    assert_eq!(regions.brightness(&origin, &corner).unwrap(), 14687245);
+    assert_eq!(regions.row_histogram().iter().sum::<i64>(), 14687245);
+    assert_eq!(regions.column_histogram().iter().sum::<i64>(), 14687245);
+
+    let rectangles = [
+        (0, 0, 999, 999),
+        (13, 700, 13, 999),
+        (250, 250, 749, 260),
+        (998, 3, 999, 3),
+    ];
+    for &(x1, y1, x2, y2) in &rectangles {
+        let (start, end) = (Point { x: x1, y: y1 }, Point { x: x2, y: y2 });
+        let bulbs: Vec<i32> = (x1..=x2)
+            .flat_map(|x| (y1..=y2).map(move |y| Point { x, y }))
+            .map(|point| grid.bulb(&point).brightness())
+            .collect();
+
+        assert_eq!(
+            regions.brightness(&start, &end).unwrap(),
+            bulbs.iter().map(|&b| i64::from(b)).sum::<i64>()
+        );
+        assert_eq!(
+            regions.lit_count(&start, &end).unwrap() as usize,
+            bulbs.iter().filter(|&&b| b > 0).count()
+        );
+        assert_eq!(
+            regions.max_brightness(&start, &end).unwrap(),
+            *bulbs.iter().max().unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_light_regions_max_brightness_every_rectangle() {
+    let mut grid = LightGrid::<AdjustableBulb>::with_size(13, 9);
+    let instructions = [
+        "turn on 0,0 through 12,8",
+        "toggle 2,1 through 7,6",
+        "turn on 5,5 through 5,5",
+        "toggle 11,0 through 12,3",
+        "turn off 3,2 through 4,2",
+        "toggle 0,7 through 9,8",
+    ];
+    for instruction in &instructions {
+        let instruction = grid.parse_instruction(instruction).unwrap();
+        grid.apply_operation(&instruction).unwrap();
+    }
+
+    let regions = grid.regions();
+    for (x1, y1) in (0..13).flat_map(|x| (0..9).map(move |y| (x, y))) {
+        for (x2, y2) in (x1..13).flat_map(|x| (y1..9).map(move |y| (x, y))) {
+            let expected = (x1..=x2)
+                .flat_map(|x| (y1..=y2).map(move |y| Point { x, y }))
+                .map(|point| grid.bulb(&point).brightness())
+                .max()
+                .unwrap();
+            let (start, end) = (Point { x: x1, y: y1 }, Point { x: x2, y: y2 });
+            assert_eq!(regions.max_brightness(&start, &end).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn test_light_regions_rejects_invalid_rectangles() {
+    let grid = LightGrid::<SimpleBulb>::with_size(5, 5);
+    let regions = grid.regions();
+    assert!(matches!(
+        regions.lit_count(&Point { x: 0, y: 0 }, &Point { x: 5, y: 0 }),
+        Err(InstructionError::OutsideGrid { .. })
+    ));
+    assert!(matches!(
+        regions.max_brightness(&Point { x: 0, y: 4 }, &Point { x: 0, y: 3 }),
+        Err(InstructionError::Inverted { .. })
+    ));
+    assert_eq!(
+        regions
+            .max_brightness(&Point { x: 0, y: 0 }, &Point { x: 4, y: 4 })
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_light_instruction_from_str_extended_grammar() {
+    let instruction = LightInstruction::from_str("set 5 0,0 through 9,9").unwrap();
+    assert_eq!(instruction.operation, Operation::Set(5));
+    assert_eq!(instruction.channel, None);
+
+    let instruction = LightInstruction::from_str("dim 2 red 1,2 through 3,4").unwrap();
+    assert_eq!(instruction.operation, Operation::Dim(2));
+    assert_eq!(instruction.channel, Some(Channel::Red));
+    assert_eq!(instruction.start_point, Point { x: 1, y: 2 });
+
+    let instruction = LightInstruction::from_str("toggle blue 0,0 through 0,0").unwrap();
+    assert_eq!(instruction.operation, Operation::Toggle);
+    assert_eq!(instruction.channel, Some(Channel::Blue));
+
+    assert!(LightInstruction::from_str("set 0,0 through 9,9").is_err());
+    assert!(LightInstruction::from_str("dim -1 0,0 through 9,9").is_err());
+    assert!(LightInstruction::from_str("turn on purple 0,0 through 9,9").is_err());
+    assert!(LightInstruction::from_str("set 99999999999 0,0 through 9,9").is_err());
+}
+
+#[test]
+fn test_dimmer_bulbs() {
+    let mut grid = LightGrid::<DimmerBulb<4>>::with_size(10, 10);
+    for instruction in &[
+        "toggle 0,0 through 9,9",
+        "toggle 0,0 through 9,9",
+        "turn on 0,0 through 4,9",
+        "dim 3 0,0 through 9,0",
+        "set 2 9,9 through 9,9",
+    ] {
+        let instruction = grid.parse_instruction(instruction).unwrap();
+        grid.apply_operation(&instruction).unwrap();
+    }
+    assert_eq!(grid.bulb(&Point { x: 0, y: 0 }).brightness(), 1);
+    assert_eq!(grid.bulb(&Point { x: 0, y: 1 }).brightness(), 4);
+    assert_eq!(grid.bulb(&Point { x: 9, y: 9 }).brightness(), 2);
+    assert_eq!(grid.total_brightness(), 10 + 90 * 4 - 2);
+}
+
+#[test]
+fn test_color_bulbs() {
+    for &storage in &[Storage::Dense, Storage::Compressed] {
+        let mut grid = LightGrid::<ColorBulb>::with_size_and_storage(3, 1, storage);
+        for instruction in &[
+            "turn on 0,0 through 2,0",
+            "set 7 red 0,0 through 0,0",
+            "toggle green 1,0 through 2,0",
+            "dim 5 blue 0,0 through 1,0",
+        ] {
+            let instruction = grid.parse_instruction(instruction).unwrap();
+            grid.apply_operation(&instruction).unwrap();
+        }
+        assert_eq!(grid.bulb(&Point { x: 0, y: 0 }).color(), [7, 1, 0]);
+        assert_eq!(grid.bulb(&Point { x: 1, y: 0 }).color(), [1, 3, 0]);
+        assert_eq!(grid.bulb(&Point { x: 2, y: 0 }).color(), [1, 3, 1]);
+        assert_eq!(grid.total_brightness(), 17);
+
+        let mut ppm = Vec::new();
+        grid.write_ppm(&mut ppm).unwrap();
+        assert_eq!(ppm, b"P6\n3 1\n7\n\x07\x01\x00\x01\x03\x00\x01\x03\x01");
+    }
+}
+
+#[test]
+fn test_bulbs_at_brightest_level() {
+    for &storage in &[Storage::Dense, Storage::Compressed] {
+        let mut grid = LightGrid::<AdjustableBulb>::with_size_and_storage(2, 1, storage);
+        for instruction in &[
+            "set 2147483647 0,0 through 1,0",
+            "toggle 0,0 through 0,0",
+            "turn on 1,0 through 1,0",
+        ] {
+            let instruction = grid.parse_instruction(instruction).unwrap();
+            grid.apply_operation(&instruction).unwrap();
+        }
+        assert_eq!(grid.bulb(&Point { x: 0, y: 0 }).brightness(), i32::MAX);
+        assert_eq!(grid.total_brightness(), 2 * i64::from(i32::MAX));
+
+        let mut grid = LightGrid::<ColorBulb>::with_size_and_storage(2, 1, storage);
+        for instruction in &[
+            "set 2000000000 0,0 through 1,0",
+            "toggle red 1,0 through 1,0",
+        ] {
+            let instruction = grid.parse_instruction(instruction).unwrap();
+            grid.apply_operation(&instruction).unwrap();
+        }
+        assert_eq!(
+            grid.bulb(&Point { x: 1, y: 0 }).color(),
+            [2_000_000_002, 2_000_000_000, 2_000_000_000]
+        );
+        assert_eq!(grid.bulb(&Point { x: 1, y: 0 }).brightness(), i32::MAX);
+        assert_eq!(grid.total_brightness(), 2 * i64::from(i32::MAX));
+    }
+
+    let mut bulb = DimmerBulb::<{ i32::MAX }>::default();
+    bulb.set(i32::MAX);
+    bulb.toggle();
+    assert_eq!(bulb.brightness(), i32::MAX);
+}
+
+#[test]
+fn test_extended_operations_on_plain_bulbs() {
+    let mut grid = LightGrid::<SimpleBulb>::with_size(4, 1);
+    for instruction in &[
+        "set 3 0,0 through 2,0",
+        "dim 1 1,0 through 1,0",
+        "set 0 2,0 through 2,0",
+        "turn on red 3,0 through 3,0",
+    ] {
+        let instruction = grid.parse_instruction(instruction).unwrap();
+        grid.apply_operation(&instruction).unwrap();
+    }
+    assert_eq!(grid.total_brightness(), 2);
+
+    let mut grid = LightGrid::<AdjustableBulb>::with_size(2, 1);
+    for instruction in &[
+        "set 6 0,0 through 1,0",
+        "dim 2 0,0 through 0,0",
+        "dim 9 1,0 through 1,0",
+    ] {
+        let instruction = grid.parse_instruction(instruction).unwrap();
+        grid.apply_operation(&instruction).unwrap();
+    }
+    assert_eq!(grid.bulb(&Point { x: 0, y: 0 }).brightness(), 4);
+    assert_eq!(grid.bulb(&Point { x: 1, y: 0 }).brightness(), 0);
+}
+
+/// A bulb relying on the default extended operations, stepping its brightness from zero to three.
+#[derive(Clone, Default)]
+struct SteppedBulb {
+    step: i32,
+}
+
+impl Bulb for SteppedBulb {
+    fn toggle(&mut self) {
+        self.step = 3 - self.step;
+    }
+
+    fn turn_on(&mut self) {
+        self.step = (self.step + 1).min(3);
+    }
+
+    fn turn_off(&mut self) {
+        self.step = (self.step - 1).max(0);
+    }
+
+    fn brightness(&self) -> i32 {
+        self.step
+    }
+}
+
+#[test]
+fn test_extended_operations_default_to_basic_operations() {
+    let mut bulb = SteppedBulb::default();
+    Operation::Set(2).apply(&mut bulb);
+    assert_eq!(bulb.brightness(), 2);
+    Operation::Set(9).apply(&mut bulb);
+    assert_eq!(bulb.brightness(), 3);
+    Operation::Dim(2).apply(&mut bulb);
+    assert_eq!(bulb.brightness(), 1);
+
+    bulb.apply_to_channel(Channel::Green, Operation::Toggle);
+    assert_eq!(bulb.color(), [2, 2, 2]);
+}
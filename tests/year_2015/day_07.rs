@@ -1,7 +1,121 @@
 use std::fs;
 use std::str::FromStr;
 
-use advent_of_code::year_2015::day_07::{Circuit, CircuitInstruction};
+use advent_of_code::solution::Solution;
+use advent_of_code::util::parse_lines;
+use advent_of_code::year_2015::day_07::{
+    Circuit, CircuitError, CircuitInstruction, Day07, Gate, InvalidWireError, Optimization, Signal,
+};
+
+#[test]
+fn test_circuit_instruction_from_str_bad_input() {
+    assert!(CircuitInstruction::from_str("x AND y").is_err());
+    assert!(CircuitInstruction::from_str("x XOR y -> z").is_err());
+
+    let err = CircuitInstruction::from_str("65536 -> b").unwrap_err();
+    assert_eq!(err.line(), "65536 -> b");
+    assert!(CircuitInstruction::from_str("x AND 70000 -> z").is_err());
+}
+
+fn build_circuit(instructions: &str) -> Circuit {
+    let mut circuit = Circuit::new();
+    parse_lines::<CircuitInstruction>(instructions)
+        .unwrap()
+        .into_iter()
+        .for_each(|instruction| circuit.add_instruction(instruction));
+    circuit
+}
+
+#[test]
+fn test_circuit_resolve_undefined_wire() {
+    let mut circuit = build_circuit("123 -> x\nx OR y -> z\nz -> a");
+    assert_eq!(
+        circuit.resolve(),
+        Err(CircuitError::UndefinedWire {
+            wire: String::from("y"),
+            input_of: String::from("z"),
+        })
+    );
+}
+
+#[test]
+fn test_circuit_resolve_cycle() {
+    let mut circuit = build_circuit("b -> a\nNOT c -> b\na AND 1 -> c\n5 -> d");
+    let err = circuit.resolve().unwrap_err();
+    assert_eq!(
+        err,
+        CircuitError::Cycle(vec![
+            String::from("a"),
+            String::from("b"),
+            String::from("c"),
+            String::from("a"),
+        ])
+    );
+    assert_eq!(err.to_string(), "the wires form a loop: a -> b -> c -> a");
+
+    let mut circuit = build_circuit("x AND x -> x");
+    assert_eq!(
+        circuit.resolve(),
+        Err(CircuitError::Cycle(vec![
+            String::from("x"),
+            String::from("x")
+        ]))
+    );
+}
+
+/// Names the wire numbered `i` with lowercase letters, as `w` followed by `i` in base 26 from `a` to `z`.
+fn numbered_wire(mut i: usize) -> String {
+    let mut name = vec![b'w'];
+    let start = name.len();
+    loop {
+        name.push(b'a' + (i % 26) as u8);
+        i /= 26;
+        if i == 0 {
+            break;
+        }
+    }
+    name[start..].reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Builds a chain of 100000 wires each left-shifting the previous one, with wire `a` at its tail.
+fn deep_chain(head: &str) -> String {
+    let mut instructions = format!("{} -> {}\n", head, numbered_wire(0));
+    for i in 1..100_000 {
+        instructions.push_str(&format!(
+            "{} LSHIFT 1 -> {}\n",
+            numbered_wire(i - 1),
+            numbered_wire(i)
+        ));
+    }
+    instructions.push_str(&format!("{} -> a\n", numbered_wire(99_999)));
+    instructions
+}
+
+#[test]
+fn test_circuit_resolve_deep_chain() {
+    // The tail of the chain is wire `a`, so it is resolved first and the whole chain is searched in one path.
+    let mut circuit = build_circuit(&deep_chain("1"));
+    circuit.resolve().unwrap();
+
+    assert_eq!(circuit.signal(&numbered_wire(15)), Some(1 << 15));
+    assert_eq!(circuit.signal(&numbered_wire(16)), Some(0));
+    assert_eq!(circuit.signal("a"), Some(0));
+}
+
+#[test]
+fn test_circuit_resolve_deep_cycle() {
+    let instructions = deep_chain(&numbered_wire(99_999));
+    let mut circuit = build_circuit(&instructions);
+
+    match circuit.resolve() {
+        Err(CircuitError::Cycle(cycle)) => {
+            assert_eq!(cycle.len(), 100_001);
+            assert_eq!(cycle.first(), cycle.last());
+        }
+        result => panic!("expected a cycle, found {:?}", result),
+    }
+}
 
 #[test]
 fn test_small_circuit() {
@@ -9,12 +123,11 @@ fn test_small_circuit() {
         fs::read_to_string("input/2015/day-07-sample.txt").expect("Failed to read file to string.");
 
     let mut circuit = Circuit::new();
-    contents
-        .lines()
-        .map(|s| CircuitInstruction::from_str(s))
-        .filter_map(Result::ok)
+    parse_lines::<CircuitInstruction>(&contents)
+        .unwrap()
+        .into_iter()
         .for_each(|instruction| circuit.add_instruction(instruction));
-    circuit.resolve();
+    circuit.resolve().unwrap();
 
     assert_eq!(circuit.signal("d"), Some(72));
     assert_eq!(circuit.signal("e"), Some(507));
@@ -32,12 +145,11 @@ fn test_circuit_resolve_input_file() {
         fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
 
     let mut circuit = Circuit::new();
-    contents
-        .lines()
-        .map(|s| CircuitInstruction::from_str(s))
-        .filter_map(Result::ok)
+    parse_lines::<CircuitInstruction>(&contents)
+        .unwrap()
+        .into_iter()
         .for_each(|instruction| circuit.add_instruction(instruction));
-    circuit.resolve();
+    circuit.resolve().unwrap();
 
     let signal = circuit.signal("a");
     assert_eq!(signal, Some(16076));
@@ -49,17 +161,394 @@ fn test_circuit_resolve_input_file_and_extra_instruction() {
         fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
 
     let mut circuit = Circuit::new();
-    contents
-        .lines()
-        .map(|s| CircuitInstruction::from_str(s))
-        .filter_map(Result::ok)
+    parse_lines::<CircuitInstruction>(&contents)
+        .unwrap()
+        .into_iter()
         .for_each(|instruction| circuit.add_instruction(instruction));
+    circuit.resolve().unwrap();
+    let signal = circuit.signal("a").unwrap();
 
-    if let Ok(instruction) = CircuitInstruction::from_str("16076 -> b") {
+    circuit.reset();
+    if let Ok(instruction) = CircuitInstruction::from_str(&format!("{} -> b", signal)) {
         circuit.add_instruction(instruction);
     };
-    circuit.resolve();
+    circuit.resolve().unwrap();
 
     let signal = circuit.signal("a");
     assert_eq!(signal, Some(2797));
 }
+
+#[test]
+fn test_circuit_override_wire() {
+    let mut circuit = build_circuit("123 -> x\n456 -> y\nx AND y -> d\nd OR y -> e\nNOT y -> i");
+    circuit.resolve().unwrap();
+    assert_eq!(circuit.signal("e"), Some(456));
+
+    assert_eq!(circuit.override_wire("x", 0xffff).unwrap(), 3);
+    assert_eq!(circuit.signal("d"), Some(456));
+    assert_eq!(circuit.signal("e"), Some(456));
+    assert_eq!(circuit.signal("i"), Some(65079));
+
+    assert_eq!(circuit.override_wire("y", 1).unwrap(), 4);
+    assert_eq!(circuit.signal("d"), Some(1));
+    assert_eq!(circuit.signal("e"), Some(1));
+    assert_eq!(circuit.signal("i"), Some(65534));
+
+    assert_eq!(circuit.override_wire("d", 2).unwrap(), 2);
+    assert_eq!(circuit.signal("e"), Some(3));
+}
+
+#[test]
+fn test_circuit_override_wire_replaces_gate() {
+    let mut circuit = build_circuit("1 -> x\n2 -> y\nx OR y -> z");
+    circuit.resolve().unwrap();
+    circuit.override_wire("z", 7).unwrap();
+    assert_eq!(circuit.signal("z"), Some(7));
+
+    assert_eq!(circuit.override_wire("x", 4).unwrap(), 1);
+    assert_eq!(circuit.signal("z"), Some(7));
+}
+
+#[test]
+fn test_circuit_override_wire_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
+
+    let mut circuit = build_circuit(&contents);
+    circuit.resolve().unwrap();
+    let signal = circuit.signal("a").unwrap();
+
+    let resolved = circuit.override_wire("b", signal).unwrap();
+    assert!(resolved < contents.lines().count());
+    assert_eq!(circuit.signal("a"), Some(2797));
+}
+
+#[test]
+fn test_circuit_write_dot() {
+    let contents =
+        fs::read_to_string("input/2015/day-07-sample.txt").expect("Failed to read file to string.");
+    let mut circuit = build_circuit(&contents);
+
+    let mut dot = Vec::new();
+    circuit.write_dot(&mut dot).unwrap();
+    let dot = String::from_utf8(dot).unwrap();
+    assert!(dot.starts_with("digraph circuit {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("    \"d\" [shape=box];\n"));
+    assert!(dot.contains("    \"e\" [shape=diamond];\n"));
+    assert!(dot.contains("    \"h\" [shape=invtriangle];\n"));
+    assert!(dot.contains("    \"g\" [shape=rarrow];\n"));
+    assert!(dot.contains("    \"x\" -> \"d\";\n"));
+    assert!(dot.contains("    \"y\" -> \"d\";\n"));
+
+    circuit.resolve().unwrap();
+    let mut dot = Vec::new();
+    circuit.write_dot(&mut dot).unwrap();
+    let dot = String::from_utf8(dot).unwrap();
+    assert!(dot.contains("    \"d\" [shape=box, label=\"d\\n72\"];\n"));
+    assert!(dot.contains("    \"f\" [shape=larrow, label=\"f\\n492\"];\n"));
+    assert_eq!(dot.matches(" -> ").count(), 12);
+}
+
+/// A small xorshift generator, so the generated instructions are the same on every run.
+struct Generator(u64);
+
+impl Generator {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn wire(&mut self) -> String {
+        let length = 1 + self.next() % 3;
+        (0..length)
+            .map(|_| (b'a' + (self.next() % 26) as u8) as char)
+            .collect()
+    }
+
+    /// Returns a name that is often not a wire, such as `""`, `"123"`, `"a b"` or `"AND"`.
+    fn name(&mut self) -> String {
+        const KEYWORDS: [&str; 3] = ["AND", "NOT", "RSHIFT"];
+        const CHARACTERS: &[u8] = b"abxyzAZ09 _";
+        match self.next() % 4 {
+            0 => String::from(KEYWORDS[(self.next() % 3) as usize]),
+            1 => self.wire(),
+            _ => {
+                let length = self.next() % 4;
+                (0..length)
+                    .map(|_| CHARACTERS[(self.next() % CHARACTERS.len() as u64) as usize] as char)
+                    .collect()
+            }
+        }
+    }
+
+    fn signal(&mut self, name: fn(&mut Generator) -> String) -> Signal {
+        if self.next().is_multiple_of(2) {
+            Signal::Source(self.next() as u16)
+        } else {
+            Signal::Wire(name(self))
+        }
+    }
+
+    fn gate(&mut self, name: fn(&mut Generator) -> String) -> Gate {
+        match self.next() % 6 {
+            0 => Gate::NoOp(self.signal(name)),
+            1 => Gate::Not(self.signal(name)),
+            2 => Gate::And(self.signal(name), self.signal(name)),
+            3 => Gate::Or(self.signal(name), self.signal(name)),
+            4 => Gate::LeftShift(self.signal(name), self.signal(name)),
+            _ => Gate::RightShift(self.signal(name), self.signal(name)),
+        }
+    }
+
+    fn instruction(&mut self) -> CircuitInstruction {
+        let gate = self.gate(Generator::wire);
+        CircuitInstruction::new(&self.wire(), gate).unwrap()
+    }
+}
+
+#[test]
+fn test_circuit_instruction_display() {
+    let instruction = CircuitInstruction::new(
+        "d",
+        Gate::And(Signal::Wire(String::from("x")), Signal::Source(7)),
+    )
+    .unwrap();
+    assert_eq!(instruction.to_string(), "x AND 7 -> d");
+    assert_eq!(instruction.wire(), "d");
+    assert_eq!(instruction.gate().inputs().collect::<Vec<_>>(), vec!["x"]);
+}
+
+#[test]
+fn test_circuit_instruction_round_trip() {
+    let mut generator = Generator(0x2015_0007);
+    for _ in 0..10_000 {
+        let instruction = generator.instruction();
+        let parsed = CircuitInstruction::from_str(&instruction.to_string()).unwrap();
+        assert_eq!(parsed, instruction);
+    }
+}
+
+#[test]
+fn test_circuit_instruction_invalid_wire() {
+    let source = || Gate::NoOp(Signal::Source(1));
+    let wire = |name: &str| Gate::NoOp(Signal::Wire(String::from(name)));
+
+    for name in &["123", "a b", "AND", "", "A", "a1"] {
+        let err = CircuitInstruction::new(name, source()).unwrap_err();
+        assert_eq!(err, InvalidWireError(name.to_string()));
+        assert!(CircuitInstruction::new("d", wire(name)).is_err());
+    }
+}
+
+#[test]
+fn test_circuit_instruction_round_trip_any_name() {
+    let is_wire = |name: &str| !name.is_empty() && name.bytes().all(|b| b.is_ascii_lowercase());
+
+    let mut generator = Generator(0x2015_0017);
+    let mut accepted = 0;
+    for _ in 0..10_000 {
+        let wire = generator.name();
+        let gate = generator.gate(Generator::name);
+        let valid = is_wire(&wire) && gate.inputs().all(is_wire);
+        match CircuitInstruction::new(&wire, gate) {
+            Ok(instruction) => {
+                assert!(valid, "accepted {}", instruction);
+                let parsed = CircuitInstruction::from_str(&instruction.to_string()).unwrap();
+                assert_eq!(parsed, instruction);
+                accepted += 1;
+            }
+            Err(_) => assert!(!valid),
+        }
+    }
+    assert!(accepted > 0);
+}
+
+#[test]
+fn test_circuit_instruction_round_trip_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
+
+    for line in contents.lines() {
+        let instruction = CircuitInstruction::from_str(line).unwrap();
+        assert_eq!(instruction.to_string(), line);
+    }
+}
+
+#[test]
+fn test_circuit_round_trip() {
+    let mut generator = Generator(0x2015_0007);
+    let circuit: Circuit = (0..500).map(|_| generator.instruction()).collect();
+
+    let parsed = Circuit::from_str(&circuit.to_string()).unwrap();
+    assert_eq!(parsed.instructions(), circuit.instructions());
+    assert_eq!(parsed.to_string(), circuit.to_string());
+}
+
+#[test]
+fn test_circuit_built_programmatically() {
+    let circuit: Circuit = vec![
+        CircuitInstruction::new("x", Gate::NoOp(Signal::Source(123))).unwrap(),
+        CircuitInstruction::new("h", Gate::Not(Signal::Wire(String::from("x")))).unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(circuit.to_string(), "NOT x -> h\n123 -> x\n");
+    assert_eq!(
+        circuit.gate("h"),
+        Some(&Gate::Not(Signal::Wire(String::from("x"))))
+    );
+    assert_eq!(circuit.gate("z"), None);
+}
+
+#[test]
+fn test_circuit_optimize() {
+    let contents =
+        fs::read_to_string("input/2015/day-07-sample.txt").expect("Failed to read file to string.");
+    let mut circuit = build_circuit(&contents);
+
+    let optimization = circuit.optimize(&["d", "h", "z"]).unwrap();
+    assert_eq!(
+        optimization,
+        Optimization {
+            folded: 2,
+            collapsed: 3,
+            removed: 6,
+        }
+    );
+    assert_eq!(circuit.to_string(), "72 -> d\n65412 -> h\n");
+    assert_eq!(
+        circuit.optimize(&["d", "h"]).unwrap(),
+        Optimization::default()
+    );
+}
+
+#[test]
+fn test_circuit_optimize_keeps_signals() {
+    let mut circuit = build_circuit("1 -> x\nx -> y\ny -> z\nz OR 2 -> a");
+    circuit.resolve().unwrap();
+
+    let optimization = circuit.optimize(&["a", "z"]).unwrap();
+    assert_eq!(optimization.removed, 2);
+    assert_eq!(circuit.to_string(), "3 -> a\n1 -> z\n");
+    assert_eq!(circuit.signal("a"), Some(3));
+    assert_eq!(circuit.signal("x"), None);
+
+    circuit.override_wire("z", 4).unwrap();
+    assert_eq!(circuit.signal("a"), Some(3));
+}
+
+#[test]
+fn test_circuit_optimize_cycle() {
+    let mut circuit = build_circuit("b -> a\nNOT a -> b\n1 -> c");
+    assert!(matches!(
+        circuit.optimize(&["a"]),
+        Err(CircuitError::Cycle(_))
+    ));
+    assert_eq!(circuit.to_string(), "b -> a\nNOT a -> b\n1 -> c\n");
+}
+
+#[test]
+fn test_circuit_optimize_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
+    let mut circuit = build_circuit(&contents);
+
+    let optimization = circuit.optimize(&["a"]).unwrap();
+    assert_eq!(optimization.removed, contents.lines().count() - 1);
+    assert_eq!(circuit.to_string(), "16076 -> a\n");
+}
+
+#[test]
+fn test_circuit_solve_single_input() {
+    let circuit = build_circuit("x AND 255 -> y\ny LSHIFT 8 -> a");
+    let solutions = circuit.solve(&["x"], "a", 0x3400).unwrap();
+    assert_eq!(solutions.len(), 256);
+    assert!(solutions
+        .iter()
+        .all(|signals| signals.len() == 1 && signals[0] & 0xff == 0x34));
+
+    assert!(circuit.solve(&["x"], "a", 0x3401).unwrap().is_empty());
+    assert_eq!(circuit.solve_first(&["x"], "a", 0x3401).unwrap(), None);
+}
+
+#[test]
+fn test_circuit_solve_multiple_inputs() {
+    let circuit = build_circuit("x AND y -> a");
+    let solutions = circuit.solve(&["x", "y"], "a", 0xfff0).unwrap();
+    assert_eq!(solutions.len(), 81);
+    assert!(solutions
+        .iter()
+        .all(|signals| signals[0] & signals[1] == 0xfff0));
+
+    let circuit = build_circuit("x OR y -> c\nNOT c -> a");
+    assert_eq!(
+        circuit.solve(&["x", "y"], "a", 0xffff).unwrap(),
+        vec![vec![0, 0]]
+    );
+}
+
+#[test]
+fn test_circuit_solve_shift_amount() {
+    let circuit = build_circuit("x LSHIFT s -> a");
+    let solutions = circuit.solve(&["s", "x"], "a", 0x8000).unwrap();
+    assert_eq!(solutions.len(), 65535);
+    assert!(solutions
+        .iter()
+        .all(|signals| signals[0] < 16 && signals[1] << signals[0] == 0x8000));
+}
+
+#[test]
+fn test_circuit_solve_matches_resolve() {
+    let instructions = "x AND y -> d\nx OR z -> e\nd LSHIFT 3 -> f\nNOT e -> g\nf OR g -> a";
+    let circuit = build_circuit(instructions);
+    let mut resolved = build_circuit(instructions);
+    let solution = circuit
+        .solve_first(&["x", "y", "z"], "a", 0x1234)
+        .unwrap()
+        .unwrap();
+    for (wire, &signal) in ["x", "y", "z"].iter().zip(&solution) {
+        resolved.add_instruction(
+            CircuitInstruction::new(wire, Gate::NoOp(Signal::Source(signal))).unwrap(),
+        );
+    }
+    resolved.resolve().unwrap();
+    assert_eq!(resolved.signal("a"), Some(0x1234));
+}
+
+#[test]
+fn test_circuit_solve_errors() {
+    let circuit = build_circuit("x AND y -> a\nb -> c");
+    assert!(matches!(
+        circuit.solve(&["x"], "a", 0),
+        Err(CircuitError::UndefinedWire { .. })
+    ));
+    assert!(circuit.solve(&["x", "y"], "z", 0).unwrap().is_empty());
+}
+
+#[test]
+fn test_circuit_solve_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
+    let circuit = build_circuit(&contents);
+
+    let solutions = circuit.solve(&["b"], "a", 16076).unwrap();
+    assert!(solutions.contains(&vec![19138]));
+
+    let solution = circuit.solve_first(&["b"], "a", 2797).unwrap().unwrap();
+    let mut resolved = build_circuit(&contents);
+    resolved.override_wire("b", solution[0]).unwrap();
+    assert_eq!(resolved.signal("a"), Some(2797));
+}
+
+#[test]
+fn test_solution_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-07.txt").expect("Failed to read file to string.");
+
+    let input = Day07::parse(&contents).unwrap();
+    assert_eq!(Day07::part_one(&input).unwrap(), 16076);
+    assert_eq!(Day07::part_two(&input).unwrap(), 2797);
+}
@@ -4,7 +4,7 @@ use advent_of_code::year_2015::day_08::{escape_string, reformat_string};
 
 #[test]
 fn test_reformat_string() {
-    let list = vec![
+    let list = [
         String::from(r#""""#),
         String::from(r#""abc""#),
         String::from(r#""aaa\"aaa""#),
@@ -18,7 +18,7 @@ fn test_reformat_string() {
 
 #[test]
 fn test_escape_string() {
-    let list = vec![
+    let list = [
         String::from(r#""""#),
         String::from(r#""abc""#),
         String::from(r#""aaa\"aaa""#),
@@ -1,7 +1,9 @@
 use std::fs;
 use std::str::FromStr;
 
-use advent_of_code::year_2015::day_09::{Edge, Graph};
+use advent_of_code::solution::Solution;
+use advent_of_code::util::parse_lines;
+use advent_of_code::year_2015::day_09::{Day09, Edge, Graph};
 
 #[test]
 fn test_shortest_path() {
@@ -59,10 +61,9 @@ fn test_shortest_path_input_file() {
         fs::read_to_string("input/2015/day-09.txt").expect("Failed to read file to string.");
 
     let mut graph = Graph::new();
-    contents
-        .lines()
-        .map(|s| Edge::from_str(s))
-        .filter_map(Result::ok)
+    parse_lines::<Edge>(&contents)
+        .unwrap()
+        .into_iter()
         .for_each(|edge| graph.add_edge(edge));
 
     let min = graph.shortest_path();
@@ -75,12 +76,22 @@ fn test_longest_path_input_file() {
         fs::read_to_string("input/2015/day-09.txt").expect("Failed to read file to string.");
 
     let mut graph = Graph::new();
-    contents
-        .lines()
-        .map(|s| Edge::from_str(s))
-        .filter_map(Result::ok)
+    parse_lines::<Edge>(&contents)
+        .unwrap()
+        .into_iter()
         .for_each(|edge| graph.add_edge(edge));
 
     let max = graph.longest_path();
     assert_eq!(max, 804);
 }
+
+#[test]
+fn test_solution_empty_input() {
+    let input = Day09::parse("").unwrap();
+    assert!(Day09::part_one(&input).is_err());
+    assert!(Day09::part_two(&input).is_err());
+
+    let (input, rejected) = Day09::parse_lenient("London to Dublin").unwrap();
+    assert_eq!(rejected.len(), 1);
+    assert!(Day09::part_one(&input).is_err());
+}
@@ -1,3 +1,5 @@
+use std::fs;
+
 use advent_of_code::year_2015::day_10::look_and_say;
 
 #[test]
@@ -19,8 +21,11 @@ fn test_look_and_say() {
 }
 
 #[test]
-fn test_look_and_say_input_40_times() {
-    let mut input = String::from("1113122113");
+fn test_look_and_say_input_file_40_times() {
+    let contents =
+        fs::read_to_string("input/2015/day-10.txt").expect("Failed to read file to string.");
+
+    let mut input = String::from(contents.trim());
     for _ in 0..40 {
         input = look_and_say(&input);
     }
@@ -30,8 +35,11 @@ fn test_look_and_say_input_40_times() {
 }
 
 #[test]
-fn test_look_and_say_input_50_times() {
-    let mut input = String::from("1113122113");
+fn test_look_and_say_input_file_50_times() {
+    let contents =
+        fs::read_to_string("input/2015/day-10.txt").expect("Failed to read file to string.");
+
+    let mut input = String::from(contents.trim());
     for _ in 0..50 {
         input = look_and_say(&input);
     }
@@ -1,3 +1,5 @@
+use std::fs;
+
 use advent_of_code::year_2015::day_11::next_password;
 
 #[test]
@@ -13,14 +15,21 @@ fn test_next_password_forbidden_letter() {
 }
 
 #[test]
-fn test_next_password_input() {
-    let new_password = next_password("cqjxjnds").unwrap();
+fn test_next_password_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-11.txt").expect("Failed to read file to string.");
+
+    let new_password = next_password(contents.trim()).unwrap();
     assert_eq!(new_password, "cqjxxyzz");
 }
 
 #[test]
-fn test_next_password_input2() {
-    let new_password = next_password("cqjxxyzz").unwrap();
+fn test_next_password_input_file_twice() {
+    let contents =
+        fs::read_to_string("input/2015/day-11.txt").expect("Failed to read file to string.");
+
+    let new_password = next_password(contents.trim()).unwrap();
+    let new_password = next_password(&new_password).unwrap();
     assert_eq!(new_password, "cqkaabcc");
 }
 
@@ -1,6 +1,8 @@
 use std::fs;
+use std::io;
+use std::io::{BufReader, ErrorKind, Read};
 
-use advent_of_code::year_2015::day_12::{sum_numbers_in_str, sum_value};
+use advent_of_code::year_2015::day_12::{sum_numbers_from_reader, sum_numbers_in_str, sum_value};
 
 #[test]
 fn test_sum_numbers() {
@@ -66,3 +68,28 @@ fn test_test_sum_value_input_file() {
     let sum = sum_value(&value);
     assert_eq!(sum, 96852);
 }
+
+#[test]
+fn test_sum_numbers_from_reader_input_file() {
+    let contents = fs::read("input/2015/day-12.txt").expect("Failed to read file.");
+    let expected = sum_numbers_in_str(&String::from_utf8_lossy(&contents));
+
+    let reader = BufReader::with_capacity(3, &contents[..]);
+    assert_eq!(
+        sum_numbers_from_reader(reader).unwrap(),
+        i64::from(expected)
+    );
+}
+
+#[test]
+fn test_sum_numbers_from_reader_overflow() {
+    let reader = io::repeat(b'9').take(10_000).chain(&b",1,-2"[..]);
+    assert_eq!(sum_numbers_from_reader(BufReader::new(reader)).unwrap(), -1);
+
+    let document = format!("[{},{}]", i64::MIN, i64::MAX);
+    assert_eq!(sum_numbers_from_reader(document.as_bytes()).unwrap(), -1);
+
+    let document = format!("[{},1]", i64::MAX);
+    let err = sum_numbers_from_reader(document.as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
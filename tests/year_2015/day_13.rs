@@ -1,6 +1,7 @@
 use std::fs;
 use std::str::FromStr;
 
+use advent_of_code::util::parse_lines;
 use advent_of_code::year_2015::day_13::{SeatingPlan, SeatingPreference};
 
 #[test]
@@ -10,10 +11,9 @@ fn test_happiest_table() {
 
     let mut plan = SeatingPlan::new();
 
-    contents
-        .lines()
-        .map(|s| SeatingPreference::from_str(s))
-        .filter_map(Result::ok)
+    parse_lines::<SeatingPreference>(&contents)
+        .unwrap()
+        .into_iter()
         .for_each(|preference| plan.add_preference(preference));
 
     let max = plan.happiest_table();
@@ -26,10 +26,9 @@ fn test_happiest_table_input_file() {
         fs::read_to_string("input/2015/day-13.txt").expect("Failed to read file to string.");
 
     let mut plan = SeatingPlan::new();
-    contents
-        .lines()
-        .map(|s| SeatingPreference::from_str(s))
-        .filter_map(Result::ok)
+    parse_lines::<SeatingPreference>(&contents)
+        .unwrap()
+        .into_iter()
         .for_each(|preference| plan.add_preference(preference));
 
     let max = plan.happiest_table();
@@ -42,10 +41,9 @@ fn test_happiest_table_input_file_and_you() {
         fs::read_to_string("input/2015/day-13.txt").expect("Failed to read file to string.");
 
     let mut plan = SeatingPlan::new();
-    contents
-        .lines()
-        .map(|s| SeatingPreference::from_str(s))
-        .filter_map(Result::ok)
+    parse_lines::<SeatingPreference>(&contents)
+        .unwrap()
+        .into_iter()
         .for_each(|preference| plan.add_preference(preference));
 
     for guest in plan.guests.clone() {
@@ -1,8 +1,10 @@
 use std::fs;
 use std::str::FromStr;
 
+use advent_of_code::solution::Solution;
+use advent_of_code::util::parse_lines;
 use advent_of_code::year_2015::day_14::{
-    distance_winning_reindeer_traveled, points_awarded_winning_reindeer, Reindeer,
+    distance_winning_reindeer_traveled, points_awarded_winning_reindeer, Day14, Reindeer,
 };
 
 #[test]
@@ -50,11 +52,7 @@ fn test_distance_winning_reindeer_traveled_input_file() {
     let contents =
         fs::read_to_string("input/2015/day-14.txt").expect("Failed to read file to string.");
 
-    let reindeer: Vec<Reindeer> = contents
-        .lines()
-        .map(|s| Reindeer::from_str(s))
-        .filter_map(Result::ok)
-        .collect();
+    let reindeer: Vec<Reindeer> = parse_lines(&contents).unwrap();
 
     let max = distance_winning_reindeer_traveled(&reindeer, 2503);
     assert_eq!(max, 2640);
@@ -65,11 +63,7 @@ fn test_points_awarded_winning_reindeer_input_file() {
     let contents =
         fs::read_to_string("input/2015/day-14.txt").expect("Failed to read file to string.");
 
-    let reindeer: Vec<Reindeer> = contents
-        .lines()
-        .map(|s| Reindeer::from_str(s))
-        .filter_map(Result::ok)
-        .collect();
+    let reindeer: Vec<Reindeer> = parse_lines(&contents).unwrap();
 
     let max = points_awarded_winning_reindeer(&reindeer, 2503);
     assert_eq!(max, 1102);
@@ -86,3 +80,14 @@ fn test_distance_winning_reindeer_traveled_empty() {
 fn test_points_awarded_winning_reindeer_empty() {
     let _ = points_awarded_winning_reindeer(&Vec::new(), 2503);
 }
+
+#[test]
+fn test_solution_empty_input() {
+    let input = Day14::parse("").unwrap();
+    assert!(Day14::part_one(&input).is_err());
+    assert!(Day14::part_two(&input).is_err());
+
+    let (input, rejected) = Day14::parse_lenient("Comet can fly.").unwrap();
+    assert_eq!(rejected.len(), 1);
+    assert!(Day14::part_two(&input).is_err());
+}
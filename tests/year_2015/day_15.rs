@@ -0,0 +1,89 @@
+use std::fs;
+use std::str::FromStr;
+
+use advent_of_code::util::parse_lines;
+use advent_of_code::year_2015::day_15::{Ingredient, Recipe};
+
+#[test]
+fn test_ingredient_from_str_bad_input() {
+    assert!(Ingredient::from_str("pancakes").is_err());
+
+    assert!(Ingredient::from_str("Sugar: capacity 3, durability 0, flavor 0, texture -3").is_err());
+}
+
+#[test]
+fn test_highest_score() {
+    let contents =
+        fs::read_to_string("input/2015/day-15-sample.txt").expect("Failed to read file to string.");
+
+    let ingredients: Vec<Ingredient> = parse_lines(&contents).unwrap();
+    let recipe = Recipe::new(ingredients);
+
+    let max = recipe.highest_score(100);
+    assert_eq!(max, Some(62842880));
+}
+
+#[test]
+fn test_highest_score_with_calories() {
+    let contents =
+        fs::read_to_string("input/2015/day-15-sample.txt").expect("Failed to read file to string.");
+
+    let ingredients: Vec<Ingredient> = parse_lines(&contents).unwrap();
+    let recipe = Recipe::new(ingredients);
+
+    let max = recipe.highest_score_with_calories(100, 500);
+    assert_eq!(max, Some(57600000));
+}
+
+#[test]
+fn test_highest_score_one_ingredient() {
+    let ingredient =
+        Ingredient::from_str("Sugar: capacity 3, durability 1, flavor 2, texture 1, calories 2")
+            .unwrap();
+    let recipe = Recipe::new(vec![ingredient]);
+
+    assert_eq!(recipe.highest_score(10), Some(30 * 10 * 20 * 10));
+    assert_eq!(recipe.highest_score_with_calories(10, 20), Some(60000));
+    assert_eq!(recipe.highest_score_with_calories(10, 21), None);
+}
+
+#[test]
+fn test_highest_score_negative_properties() {
+    let ingredient =
+        Ingredient::from_str("Sugar: capacity -3, durability 1, flavor 2, texture 1, calories 2")
+            .unwrap();
+    let recipe = Recipe::new(vec![ingredient]);
+
+    assert_eq!(recipe.highest_score(100), Some(0));
+}
+
+#[test]
+fn test_highest_score_empty() {
+    let recipe = Recipe::new(Vec::new());
+    assert_eq!(recipe.highest_score(100), None);
+    assert_eq!(recipe.highest_score_with_calories(100, 500), None);
+}
+
+#[test]
+fn test_highest_score_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-15.txt").expect("Failed to read file to string.");
+
+    let ingredients: Vec<Ingredient> = parse_lines(&contents).unwrap();
+    let recipe = Recipe::new(ingredients);
+
+    let max = recipe.highest_score(100);
+    assert_eq!(max, Some(222870));
+}
+
+#[test]
+fn test_highest_score_with_calories_input_file() {
+    let contents =
+        fs::read_to_string("input/2015/day-15.txt").expect("Failed to read file to string.");
+
+    let ingredients: Vec<Ingredient> = parse_lines(&contents).unwrap();
+    let recipe = Recipe::new(ingredients);
+
+    let max = recipe.highest_score_with_calories(100, 500);
+    assert_eq!(max, Some(117936));
+}
@@ -0,0 +1,47 @@
+use std::str::FromStr;
+
+use advent_of_code::year_2015::day_16::{find_real_sue, find_sue, Sue};
+
+fn sues() -> Vec<Sue> {
+    [
+        "Sue 1: goldfish: 9, cars: 0, samoyeds: 9",
+        "Sue 2: cats: 7, trees: 3, akitas: 0",
+        "Sue 3: cats: 10, pomeranians: 1, perfumes: 1",
+        "Sue 4: children: 3, goldfish: 5, vizslas: 0",
+    ]
+    .iter()
+    .map(|s| Sue::from_str(s).unwrap())
+    .collect()
+}
+
+#[test]
+fn test_sue_from_str() {
+    let sue = Sue::from_str("Sue 12: goldfish: 9, cars: 0, samoyeds: 9").unwrap();
+    assert_eq!(sue.number, 12);
+    assert_eq!(sue.compound("goldfish"), Some(9));
+    assert_eq!(sue.compound("cars"), Some(0));
+    assert_eq!(sue.compound("trees"), None);
+}
+
+#[test]
+fn test_sue_from_str_bad_input() {
+    assert!(Sue::from_str("Sue 1 goldfish: 9").is_err());
+    assert!(Sue::from_str("Sue 1: goldfish 9").is_err());
+    assert!(Sue::from_str("Bob 1: goldfish: 9").is_err());
+}
+
+#[test]
+fn test_find_sue() {
+    assert_eq!(find_sue(&sues()), Some(2));
+}
+
+#[test]
+fn test_find_real_sue() {
+    assert_eq!(find_real_sue(&sues()), Some(3));
+}
+
+#[test]
+fn test_find_sue_no_match() {
+    assert_eq!(find_sue(&sues()[..1]), None);
+    assert_eq!(find_real_sue(&Vec::new()), None);
+}
@@ -0,0 +1,23 @@
+use advent_of_code::year_2015::day_17::{combinations, minimum_combinations};
+
+#[test]
+fn test_combinations() {
+    assert_eq!(combinations(&[20, 15, 10, 5, 5], 25), 4);
+}
+
+#[test]
+fn test_minimum_combinations() {
+    assert_eq!(minimum_combinations(&[20, 15, 10, 5, 5], 25), 3);
+}
+
+#[test]
+fn test_combinations_too_small() {
+    assert_eq!(combinations(&[20, 15, 10, 5, 5], 100), 0);
+    assert_eq!(minimum_combinations(&[20, 15, 10, 5, 5], 100), 0);
+}
+
+#[test]
+fn test_combinations_empty() {
+    assert_eq!(combinations(&[], 0), 1);
+    assert_eq!(combinations(&[], 25), 0);
+}
@@ -0,0 +1,52 @@
+use std::fs;
+use std::str::FromStr;
+
+use advent_of_code::year_2015::day_18::{lights_on_after, lights_on_after_stuck, Animation};
+
+#[test]
+fn test_animation_from_str() {
+    let animation = Animation::from_str("#.\n.#").unwrap();
+    assert_eq!(animation.size(), 2);
+    assert!(animation.is_on(0, 0));
+    assert!(!animation.is_on(1, 0));
+    assert_eq!(animation.lights_on(), 2);
+}
+
+#[test]
+fn test_animation_from_str_bad_input() {
+    assert!(Animation::from_str("#.\n.").is_err());
+    assert!(Animation::from_str("#.\n.x").is_err());
+
+    let err = Animation::from_str("#..\n...\n.#").unwrap_err();
+    assert_eq!(err.line(), ".#");
+    assert_eq!(err.line_number(), Some(3));
+}
+
+#[test]
+fn test_lights_on_after() {
+    let contents =
+        fs::read_to_string("input/2015/day-18-sample.txt").expect("Failed to read file to string.");
+    let animation = Animation::from_str(contents.trim()).unwrap();
+
+    assert_eq!(lights_on_after(&animation, 0), 15);
+    assert_eq!(lights_on_after(&animation, 1), 11);
+    assert_eq!(lights_on_after(&animation, 4), 4);
+}
+
+#[test]
+fn test_lights_on_after_stuck() {
+    let contents =
+        fs::read_to_string("input/2015/day-18-sample.txt").expect("Failed to read file to string.");
+    let animation = Animation::from_str(contents.trim()).unwrap();
+
+    assert_eq!(lights_on_after_stuck(&animation, 0), 17);
+    assert_eq!(lights_on_after_stuck(&animation, 5), 17);
+}
+
+#[test]
+fn test_step() {
+    let mut animation = Animation::from_str("....\n.##.\n.##.\n....").unwrap();
+    let block = animation.clone();
+    animation.step();
+    assert_eq!(animation, block);
+}
@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+use advent_of_code::year_2015::day_19::{Machine, Replacement};
+
+#[test]
+fn test_replacement_from_str() {
+    let replacement = Replacement::from_str("H => HO").unwrap();
+    assert_eq!(replacement.from, "H");
+    assert_eq!(replacement.to, "HO");
+
+    assert!(Replacement::from_str("H -> HO").is_err());
+}
+
+#[test]
+fn test_machine_from_str_bad_input() {
+    assert!(Machine::from_str("H => HO\nH => OH").is_err());
+    assert!(Machine::from_str("H => HO\nH OH\n\nHOH").is_err());
+    assert!(Machine::from_str("H => HO\n\n").is_err());
+
+    let err = Machine::from_str("H => HO\nH OH\n\nHOH").unwrap_err();
+    assert_eq!(err.line(), "H OH");
+    assert_eq!(err.line_number(), Some(2));
+}
+
+#[test]
+fn test_calibrate() {
+    let machine = Machine::from_str("H => HO\nH => OH\nO => HH\n\nHOH").unwrap();
+    assert_eq!(machine.calibrate(), 4);
+
+    let machine = Machine::from_str("H => HO\nH => OH\nO => HH\n\nHOHOHO").unwrap();
+    assert_eq!(machine.calibrate(), 7);
+}
+
+#[test]
+fn test_fewest_steps() {
+    let replacements = "e => H\ne => O\nH => HO\nH => OH\nO => HH\n\n";
+
+    let machine = Machine::from_str(&format!("{}HOH", replacements)).unwrap();
+    assert_eq!(machine.fewest_steps(), Some(3));
+
+    let machine = Machine::from_str(&format!("{}HOHOHO", replacements)).unwrap();
+    assert_eq!(machine.fewest_steps(), Some(6));
+}
+
+#[test]
+fn test_fewest_steps_wrapped_elements() {
+    let replacements = "e => HF\nH => CRnFYFAr\nF => CaF\nCa => SiRnMgAr\n\n";
+
+    let machine = Machine::from_str(&format!("{}CRnFYFArF", replacements)).unwrap();
+    assert_eq!(machine.fewest_steps(), Some(2));
+
+    let machine = Machine::from_str(&format!("{}CRnCaFYFArF", replacements)).unwrap();
+    assert_eq!(machine.fewest_steps(), Some(3));
+
+    let machine = Machine::from_str(&format!("{}CRnSiRnMgArFYFArF", replacements)).unwrap();
+    assert_eq!(machine.fewest_steps(), Some(4));
+}
+
+#[test]
+fn test_fewest_steps_impossible() {
+    // The medicine has an element that no replacement makes.
+    let machine = Machine::from_str("e => H\nH => HO\n\nOC").unwrap();
+    assert_eq!(machine.fewest_steps(), None);
+
+    // A replacement adds more than one element.
+    let machine = Machine::from_str("e => H\nH => HOO\n\nHOO").unwrap();
+    assert_eq!(machine.fewest_steps(), None);
+
+    // The replacements from `e` make molecules with different numbers of elements.
+    let machine = Machine::from_str("e => H\ne => HO\nH => HO\n\nHOO").unwrap();
+    assert_eq!(machine.fewest_steps(), None);
+
+    // The medicine has fewer elements than any molecule made from `e`.
+    let machine = Machine::from_str("e => HO\nH => HO\n\nH").unwrap();
+    assert_eq!(machine.fewest_steps(), None);
+
+    // A replacement is not from a single element.
+    let machine = Machine::from_str("e => H\nHO => HOO\n\nHO").unwrap();
+    assert_eq!(machine.fewest_steps(), None);
+}
+
+#[test]
+fn test_fewest_steps_not_fabricable() {
+    // Every element is made by a replacement, but only from `H`, which always stays first.
+    let machine = Machine::from_str("e => H\nH => HO\n\nOH").unwrap();
+    assert_eq!(machine.fewest_steps(), None);
+
+    let machine = Machine::from_str("e => H\nH => HO\n\nOOOO").unwrap();
+    assert_eq!(machine.fewest_steps(), None);
+
+    let machine = Machine::from_str("e => H\nH => HO\n\nHOOO").unwrap();
+    assert_eq!(machine.fewest_steps(), Some(4));
+}
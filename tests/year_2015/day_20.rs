@@ -0,0 +1,17 @@
+use advent_of_code::year_2015::day_20::{lowest_house_number, lowest_house_number_lazy_elves};
+
+#[test]
+fn test_lowest_house_number() {
+    assert_eq!(lowest_house_number(10), 1);
+    assert_eq!(lowest_house_number(60), 4);
+    assert_eq!(lowest_house_number(70), 4);
+    assert_eq!(lowest_house_number(120), 6);
+    assert_eq!(lowest_house_number(150), 8);
+}
+
+#[test]
+fn test_lowest_house_number_lazy_elves() {
+    assert_eq!(lowest_house_number_lazy_elves(11), 1);
+    assert_eq!(lowest_house_number_lazy_elves(70), 4);
+    assert_eq!(lowest_house_number_lazy_elves(11 * 28), 12);
+}
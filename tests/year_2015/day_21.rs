@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+use advent_of_code::year_2015::day_21::{
+    least_gold_to_win, most_gold_to_lose, Character, ARMOR, RINGS, WEAPONS,
+};
+
+#[test]
+fn test_character_from_str() {
+    let boss = Character::from_str("Hit Points: 104\nDamage: 8\nArmor: 1\n").unwrap();
+    assert_eq!(
+        boss,
+        Character {
+            hit_points: 104,
+            damage: 8,
+            armor: 1
+        }
+    );
+
+    assert!(Character::from_str("Hit Points: 104\nDamage: 8").is_err());
+}
+
+#[test]
+fn test_defeats() {
+    let player = Character {
+        hit_points: 8,
+        damage: 5,
+        armor: 5,
+    };
+    let boss = Character {
+        hit_points: 12,
+        damage: 7,
+        armor: 2,
+    };
+    assert!(player.defeats(&boss));
+
+    let boss = Character {
+        hit_points: 13,
+        damage: 7,
+        armor: 2,
+    };
+    assert!(!player.defeats(&boss));
+}
+
+#[test]
+fn test_equipped() {
+    let player = Character::equipped(100, &[WEAPONS[1], ARMOR[0], RINGS[0], RINGS[5]]);
+    assert_eq!(
+        player,
+        Character {
+            hit_points: 100,
+            damage: 6,
+            armor: 4
+        }
+    );
+}
+
+#[test]
+fn test_least_gold_to_win() {
+    let boss = Character {
+        hit_points: 100,
+        damage: 4,
+        armor: 0,
+    };
+    assert_eq!(least_gold_to_win(&boss), Some(8));
+
+    let boss = Character {
+        hit_points: 1000,
+        damage: 1000,
+        armor: 0,
+    };
+    assert_eq!(least_gold_to_win(&boss), None);
+}
+
+#[test]
+fn test_most_gold_to_lose() {
+    let boss = Character {
+        hit_points: 1000,
+        damage: 1000,
+        armor: 0,
+    };
+    assert_eq!(most_gold_to_lose(&boss), Some(74 + 102 + 100 + 80));
+
+    let boss = Character {
+        hit_points: 1,
+        damage: 0,
+        armor: 0,
+    };
+    assert_eq!(most_gold_to_lose(&boss), None);
+}
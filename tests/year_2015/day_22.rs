@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use advent_of_code::year_2015::day_22::{least_mana_to_win, Boss, Difficulty, Spell, Wizard};
+
+#[test]
+fn test_boss_from_str() {
+    let boss = Boss::from_str("Hit Points: 55\nDamage: 8\n").unwrap();
+    assert_eq!(
+        boss,
+        Boss {
+            hit_points: 55,
+            damage: 8
+        }
+    );
+
+    assert!(Boss::from_str("Hit Points: 55").is_err());
+}
+
+#[test]
+fn test_spell_cost() {
+    let total: u32 = Spell::ALL.iter().map(|spell| spell.cost()).sum();
+    assert_eq!(total, 53 + 73 + 113 + 173 + 229);
+}
+
+#[test]
+fn test_least_mana_to_win() {
+    let wizard = Wizard {
+        hit_points: 10,
+        mana: 250,
+    };
+
+    let boss = Boss {
+        hit_points: 13,
+        damage: 8,
+    };
+    assert_eq!(
+        least_mana_to_win(&wizard, &boss, Difficulty::Normal),
+        Some(173 + 53)
+    );
+
+    let boss = Boss {
+        hit_points: 14,
+        damage: 8,
+    };
+    assert_eq!(
+        least_mana_to_win(&wizard, &boss, Difficulty::Normal),
+        Some(229 + 113 + 73 + 173 + 53)
+    );
+}
+
+#[test]
+fn test_least_mana_to_win_hard() {
+    let wizard = Wizard {
+        hit_points: 10,
+        mana: 250,
+    };
+    let boss = Boss {
+        hit_points: 13,
+        damage: 8,
+    };
+    assert_eq!(least_mana_to_win(&wizard, &boss, Difficulty::Hard), None);
+}
+
+#[test]
+fn test_least_mana_to_win_not_enough_mana() {
+    let wizard = Wizard {
+        hit_points: 50,
+        mana: 52,
+    };
+    let boss = Boss {
+        hit_points: 1,
+        damage: 1,
+    };
+    assert_eq!(least_mana_to_win(&wizard, &boss, Difficulty::Normal), None);
+}
@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use advent_of_code::year_2015::day_23::{Computer, Instruction, Register};
+
+fn program(lines: &[&str]) -> Vec<Instruction> {
+    lines
+        .iter()
+        .map(|s| Instruction::from_str(s).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_instruction_from_str() {
+    assert_eq!(
+        Instruction::from_str("hlf a").unwrap(),
+        Instruction::Half(Register::A)
+    );
+    assert_eq!(
+        Instruction::from_str("tpl b").unwrap(),
+        Instruction::Triple(Register::B)
+    );
+    assert_eq!(
+        Instruction::from_str("inc a").unwrap(),
+        Instruction::Increment(Register::A)
+    );
+    assert_eq!(
+        Instruction::from_str("jmp -7").unwrap(),
+        Instruction::Jump(-7)
+    );
+    assert_eq!(
+        Instruction::from_str("jie a, +4").unwrap(),
+        Instruction::JumpIfEven(Register::A, 4)
+    );
+    assert_eq!(
+        Instruction::from_str("jio b, -2").unwrap(),
+        Instruction::JumpIfOne(Register::B, -2)
+    );
+}
+
+#[test]
+fn test_instruction_from_str_bad_input() {
+    assert!(Instruction::from_str("inc c").is_err());
+    assert!(Instruction::from_str("jmp a").is_err());
+    assert!(Instruction::from_str("hlf +2").is_err());
+    assert!(Instruction::from_str("jie a").is_err());
+    assert!(Instruction::from_str("nop").is_err());
+}
+
+#[test]
+fn test_run() {
+    let program = program(&["inc a", "jio a, +2", "tpl a", "inc a"]);
+
+    let mut computer = Computer::default();
+    computer.run(&program);
+    assert_eq!(computer.register(Register::A), 2);
+    assert_eq!(computer.register(Register::B), 0);
+}
+
+#[test]
+fn test_run_collatz() {
+    // Counts the steps for the value in a to reach 1 in the Collatz sequence.
+    let program = program(&[
+        "jio a, +8",
+        "inc b",
+        "jie a, +4",
+        "tpl a",
+        "inc a",
+        "jmp +2",
+        "hlf a",
+        "jmp -7",
+    ]);
+
+    let mut computer = Computer::new(27, 0);
+    computer.run(&program);
+    assert_eq!(computer.register(Register::A), 1);
+    assert_eq!(computer.register(Register::B), 111);
+}
@@ -0,0 +1,20 @@
+use advent_of_code::year_2015::day_24::ideal_entanglement;
+
+#[test]
+fn test_ideal_entanglement() {
+    let packages = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+    assert_eq!(ideal_entanglement(&packages, 3), Some(99));
+}
+
+#[test]
+fn test_ideal_entanglement_four_groups() {
+    let packages = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+    assert_eq!(ideal_entanglement(&packages, 4), Some(44));
+}
+
+#[test]
+fn test_ideal_entanglement_unbalanced() {
+    assert_eq!(ideal_entanglement(&[1, 2, 4], 2), None);
+    assert_eq!(ideal_entanglement(&[1, 2, 3, 10, 11, 15], 3), None);
+    assert_eq!(ideal_entanglement(&[1, 2, 3], 0), None);
+}
@@ -0,0 +1,49 @@
+use std::str::FromStr;
+
+use advent_of_code::year_2015::day_25::{code_at, Position};
+
+#[test]
+fn test_position_from_str() {
+    let position = Position::from_str(
+        "To continue, please consult the code grid in the manual.  Enter the code at row 2947, column 3029.",
+    )
+    .unwrap();
+    assert_eq!(
+        position,
+        Position {
+            row: 2947,
+            column: 3029
+        }
+    );
+
+    assert!(Position::from_str("Enter the code at row 0, column 1.").is_err());
+    assert!(Position::from_str("Enter the code.").is_err());
+}
+
+#[test]
+fn test_code_at() {
+    let codes = [
+        [20151125, 18749137, 17289845, 30943339, 10071777, 33511524],
+        [31916031, 21629792, 16929656, 7726640, 15514188, 4041754],
+        [16080970, 8057251, 1601130, 7981243, 11661866, 16474243],
+        [24592653, 32451966, 21345942, 9380097, 10600672, 31527494],
+        [77061, 17552253, 28094349, 6899651, 9250759, 31663883],
+        [33071741, 6796745, 25397450, 24659492, 1534922, 27995004],
+    ];
+
+    for (row, codes) in codes.iter().enumerate() {
+        for (column, &code) in codes.iter().enumerate() {
+            let position = Position {
+                row: row as u64 + 1,
+                column: column as u64 + 1,
+            };
+            assert_eq!(code_at(position), code);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_code_at_zero() {
+    let _ = code_at(Position { row: 0, column: 1 });
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use advent_of_code::year_2016::day_01::{
+    distance_to_destination, distance_to_first_revisit, Step, Turn,
+};
+
+fn steps(s: &str) -> Vec<Step> {
+    s.split(", ").map(|s| Step::from_str(s).unwrap()).collect()
+}
+
+#[test]
+fn test_step_from_str() {
+    assert_eq!(
+        Step::from_str("R12").unwrap(),
+        Step {
+            turn: Turn::Right,
+            blocks: 12
+        }
+    );
+    assert_eq!(
+        Step::from_str("L0").unwrap(),
+        Step {
+            turn: Turn::Left,
+            blocks: 0
+        }
+    );
+}
+
+#[test]
+fn test_step_from_str_bad_input() {
+    assert!(Step::from_str("").is_err());
+    assert!(Step::from_str("U2").is_err());
+    assert!(Step::from_str("R").is_err());
+    assert!(Step::from_str("L-2").is_err());
+}
+
+#[test]
+fn test_distance_to_destination() {
+    assert_eq!(distance_to_destination(&steps("R2, L3")), 5);
+    assert_eq!(distance_to_destination(&steps("R2, R2, R2")), 2);
+    assert_eq!(distance_to_destination(&steps("R5, L5, R5, R3")), 12);
+}
+
+#[test]
+fn test_distance_to_destination_empty() {
+    assert_eq!(distance_to_destination(&Vec::new()), 0);
+}
+
+#[test]
+fn test_distance_to_first_revisit() {
+    assert_eq!(distance_to_first_revisit(&steps("R8, R4, R4, R8")), Some(4));
+    assert_eq!(distance_to_first_revisit(&steps("R2, L3")), None);
+}
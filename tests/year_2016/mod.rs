@@ -0,0 +1 @@
+mod day_01;